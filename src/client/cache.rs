@@ -0,0 +1,235 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use tokio::{sync::broadcast, task::JoinHandle};
+
+use crate::{client::rest::KuCoinClient, types::market::SymbolInfo, utils::errors::KucoinResults};
+
+/// Capacity of the event channel; slow subscribers lagging further behind lose the oldest events.
+const EVENT_CAPACITY: usize = 1024;
+
+/// A change detected between two snapshots of the symbol list.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SymbolEvent {
+    /// A symbol appeared in the list.
+    Added(SymbolInfo),
+    /// A symbol disappeared from the list.
+    Removed(SymbolInfo),
+    /// The increments or the `enableTrading` flag of a symbol changed.
+    Changed {
+        old: Box<SymbolInfo>,
+        new: Box<SymbolInfo>,
+    },
+}
+
+/// Reference-data cache of the spot trading rules, built on `MarketHandler::symbols`.
+///
+/// Lookups are synchronous; refreshes happen either manually through `refresh` or in the
+/// background through `spawn_refresh`. Cloning the cache shares the same underlying data.
+#[derive(Clone)]
+pub struct SymbolCache {
+    symbols: Arc<RwLock<Snapshot>>,
+    events: broadcast::Sender<SymbolEvent>,
+}
+
+/// The cached symbols and whether a first snapshot has been applied yet.
+#[derive(Default)]
+struct Snapshot {
+    primed: bool,
+    symbols: HashMap<String, SymbolInfo>,
+}
+
+impl Default for SymbolCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SymbolCache {
+    /// Create an empty cache. The first update populates it without emitting events.
+    pub fn new() -> Self {
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        SymbolCache {
+            symbols: Arc::new(RwLock::new(Snapshot::default())),
+            events,
+        }
+    }
+
+    /// Trading rules of a symbol (e.g., "BTC-USDT").
+    pub fn get(&self, symbol: &str) -> Option<SymbolInfo> {
+        self.symbols.read().unwrap().symbols.get(symbol).cloned()
+    }
+
+    /// Whether the symbol is listed and currently tradable.
+    pub fn is_tradable(&self, symbol: &str) -> bool {
+        self.symbols
+            .read()
+            .unwrap()
+            .symbols
+            .get(symbol)
+            .is_some_and(|info| info.enable_trading)
+    }
+
+    /// All cached symbol codes.
+    pub fn symbols(&self) -> Vec<String> {
+        self.symbols
+            .read()
+            .unwrap()
+            .symbols
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.read().unwrap().symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.read().unwrap().symbols.is_empty()
+    }
+
+    /// Subscribe to the events emitted by subsequent updates.
+    pub fn subscribe(&self) -> broadcast::Receiver<SymbolEvent> {
+        self.events.subscribe()
+    }
+
+    /// Replace the cached snapshot with `symbols` and broadcast the differences.
+    ///
+    /// An empty list is treated as a bad response and ignored, so a single empty refresh
+    /// cannot report every symbol as removed.
+    ///
+    /// # Returns
+    /// * The emitted events; empty on the first (populating) update and on ignored updates.
+    pub fn update(&self, symbols: Vec<SymbolInfo>) -> Vec<SymbolEvent> {
+        if symbols.is_empty() {
+            return Vec::new();
+        }
+        let next: HashMap<String, SymbolInfo> = symbols
+            .into_iter()
+            .map(|info| (info.symbol.clone(), info))
+            .collect();
+
+        let mut current = self.symbols.write().unwrap();
+        let events = if current.primed {
+            diff(&current.symbols, &next)
+        } else {
+            Vec::new()
+        };
+        current.primed = true;
+        current.symbols = next;
+        drop(current);
+
+        for event in &events {
+            // No subscribers is not an error.
+            let _ = self.events.send(event.clone());
+        }
+        events
+    }
+
+    /// Fetch the symbol list once and apply it.
+    pub async fn refresh(&self, client: &KuCoinClient) -> KucoinResults<Vec<SymbolEvent>> {
+        let symbols = client.market().symbols(None).await?.into_data()?;
+        Ok(self.update(symbols))
+    }
+
+    /// Refresh the cache every `every` in a background task, starting immediately.
+    /// Failed refreshes are logged at `warn` level and retried on the next tick.
+    pub fn spawn_refresh(&self, client: KuCoinClient, every: Duration) -> JoinHandle<()> {
+        let cache = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(every);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = cache.refresh(&client).await {
+                    log::warn!("symbol cache refresh failed: {:?}", e);
+                }
+            }
+        })
+    }
+}
+
+/// Compute the events that turn `old` into `new`, sorted by symbol.
+fn diff(old: &HashMap<String, SymbolInfo>, new: &HashMap<String, SymbolInfo>) -> Vec<SymbolEvent> {
+    let mut events: Vec<(String, SymbolEvent)> = Vec::new();
+
+    for (symbol, info) in new {
+        match old.get(symbol) {
+            None => events.push((symbol.clone(), SymbolEvent::Added(info.clone()))),
+            Some(prev) if prev.rules_changed(info) => events.push((
+                symbol.clone(),
+                SymbolEvent::Changed {
+                    old: Box::new(prev.clone()),
+                    new: Box::new(info.clone()),
+                },
+            )),
+            Some(_) => {}
+        }
+    }
+    for (symbol, info) in old {
+        if !new.contains_key(symbol) {
+            events.push((symbol.clone(), SymbolEvent::Removed(info.clone())));
+        }
+    }
+
+    events.sort_by(|a, b| a.0.cmp(&b.0));
+    events.into_iter().map(|(_, event)| event).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::fixtures::{amt, symbol_info as symbol};
+
+    #[test]
+    fn test_update_emits_diff() {
+        let cache = SymbolCache::new();
+        let mut rx = cache.subscribe();
+
+        // First snapshot only populates.
+        let events = cache.update(vec![
+            symbol("AAA-USDT", "0.01", true),
+            symbol("BBB-USDT", "0.01", true),
+            symbol("CCC-USDT", "0.01", true),
+        ]);
+        assert!(events.is_empty());
+        assert_eq!(cache.len(), 3);
+
+        let events = cache.update(vec![
+            symbol("AAA-USDT", "0.001", true),
+            symbol("BBB-USDT", "0.01", true),
+            symbol("DDD-USDT", "0.01", false),
+        ]);
+
+        assert_eq!(events.len(), 3);
+        assert!(
+            matches!(&events[0], SymbolEvent::Changed { new, .. } if new.price_increment == amt("0.001"))
+        );
+        assert!(matches!(&events[1], SymbolEvent::Removed(info) if info.symbol == "CCC-USDT"));
+        assert!(matches!(&events[2], SymbolEvent::Added(info) if info.symbol == "DDD-USDT"));
+        assert_eq!(rx.try_recv().unwrap(), events[0]);
+
+        assert!(cache.get("CCC-USDT").is_none());
+        assert!(!cache.is_tradable("DDD-USDT"));
+        assert!(cache.is_tradable("AAA-USDT"));
+    }
+
+    #[test]
+    fn test_update_ignores_empty_snapshot() {
+        let cache = SymbolCache::new();
+        cache.update(vec![symbol("AAA-USDT", "0.01", true)]);
+
+        assert!(cache.update(Vec::new()).is_empty());
+        assert!(cache.is_tradable("AAA-USDT"));
+
+        let events = cache.update(vec![
+            symbol("AAA-USDT", "0.01", true),
+            symbol("BBB-USDT", "0.01", true),
+        ]);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], SymbolEvent::Added(info) if info.symbol == "BBB-USDT"));
+    }
+}
@@ -0,0 +1,30 @@
+//! Test data shared by the unit tests: amount literals and symbol trading rules.
+
+use crate::{
+    types::market::SymbolInfo,
+    utils::num::{self, Amount},
+};
+
+/// An `Amount` from a decimal literal, e.g. `amt("0.001")`.
+pub(crate) fn amt(raw: &str) -> Amount {
+    num::parse_amount(raw).unwrap()
+}
+
+/// Trading rules of `symbol` ("BASE-QUOTE"), modelled on BTC-USDT.
+pub(crate) fn symbol_info(symbol: &str, price_increment: &str, enable_trading: bool) -> SymbolInfo {
+    let (base, quote) = symbol.split_once('-').unwrap_or((symbol, "USDT"));
+    let json = format!(
+        r#"{{"symbol":"{symbol}","name":"{symbol}","baseCurrency":"{base}","quoteCurrency":"{quote}",
+        "feeCurrency":"{quote}","market":"USDS","baseMinSize":"0.00001","quoteMinSize":"0.1",
+        "baseMaxSize":"10000000000","quoteMaxSize":"99999999","baseIncrement":"0.00000001",
+        "quoteIncrement":"0.000001","priceIncrement":"{price_increment}","priceLimitRate":"0.1",
+        "minFunds":"0.1","isMarginEnabled":true,"enableTrading":{enable_trading},"feeCategory":1,
+        "makerFeeCoefficient":"1.00","takerFeeCoefficient":"1.00","st":false}}"#
+    );
+    serde_json::from_str(&json).unwrap()
+}
+
+/// Trading rules of BTC-USDT: price increment 0.1, size increment 0.00000001, min funds 0.1.
+pub(crate) fn btc_rules() -> SymbolInfo {
+    symbol_info("BTC-USDT", "0.1", true)
+}
@@ -1 +1,7 @@
+pub mod cache;
+#[cfg(test)]
+pub(crate) mod fixtures;
+#[cfg(test)]
+pub(crate) mod mock;
 pub mod rest;
+pub mod watcher;
@@ -1,14 +1,27 @@
 use secrecy::{ExposeSecret, SecretString};
 use serde::de::DeserializeOwned;
-use std::str::FromStr;
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
 use crate::{
+    client::cache::SymbolCache,
     endpoints::{
-        deposit::DepositHandler, sub_account::SubAccHander, trades::SpotHandler,
-        transfer::TransferHandler, withdrawals::WithdrawHandler,
+        account::AccountHandler, deposit::DepositHandler, margin::MarginHandler,
+        market::MarketHandler, oco::OcoHandler, portfolio::PortfolioHandler,
+        sub_account::SubAccHander, trades::SpotHandler, transfer::TransferHandler,
+        withdrawals::WithdrawHandler,
+    },
+    types::{
+        account::{ApiKeyInfo, Permission, Scope, TradeFee},
+        spot::{DcpData, DcpRequest},
     },
     utils::{
         auth::{encrypt_pass, encrypt_prehash},
+        errors::{KucoinErrors, KucoinResults},
         time,
     },
 };
@@ -16,6 +29,7 @@ use reqwest::{
     Client, Method,
     header::{CONTENT_TYPE, HeaderMap, HeaderValue, InvalidHeaderValue},
 };
+use tokio::task::JoinHandle;
 
 /// Holds API authentication secrets (Key, Secret, Passphrase).
 #[derive(Clone)]
@@ -42,7 +56,17 @@ pub struct KuCoinClient {
     credentials: Credentials,
     /// The API host URL (e.g., https://api.kucoin.com).
     pub base_link: String,
+    /// The futures API host URL (e.g., https://api-futures.kucoin.com).
+    pub futures_link: String,
     http_client: Client,
+    /// Actual trading fees per symbol, shared between clones.
+    pub(crate) fee_cache: Arc<RwLock<HashMap<String, TradeFee>>>,
+    /// When set, mutating requests are signed and logged but never sent.
+    dry_run: bool,
+    /// When set, spot orders are checked against the symbol rules before being sent.
+    validate_orders: bool,
+    /// Symbol rules used by order validation, fetched per order when unset.
+    pub(crate) symbol_cache: Option<SymbolCache>,
 }
 
 impl KuCoinClient {
@@ -57,13 +81,59 @@ impl KuCoinClient {
         KuCoinClient {
             credentials,
             base_link: "https://api.kucoin.com".to_string(),
+            futures_link: "https://api-futures.kucoin.com".to_string(),
             http_client: Client::new(),
+            fee_cache: Arc::new(RwLock::new(HashMap::new())),
+            dry_run: false,
+            validate_orders: false,
+            symbol_cache: None,
         }
     }
 
+    /// Enable or disable dry-run mode.
+    ///
+    /// In dry-run mode every mutating handler (orders, cancellations, transfers, withdrawals,
+    /// sub-account API keys) builds and signs its request, logs it at `info` level (the body at
+    /// `debug`, secrets masked) and returns a synthetic result without calling the network.
+    /// Read-only requests are still sent.
+    pub fn set_dry_run(&mut self, enabled: bool) -> &mut Self {
+        self.dry_run = enabled;
+        self
+    }
+
+    /// Whether dry-run mode is enabled.
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Enable or disable automatic order validation.
+    ///
+    /// When enabled, `SpotHandler` checks every `SpotOrderRequest` with
+    /// `SpotOrderRequest::validate` before placing it, and fails with
+    /// `KucoinErrors::OrderValidation` instead of sending an order the exchange would reject.
+    /// Rules come from the symbol cache (see `set_symbol_cache`) or are fetched per order.
+    pub fn set_validate_orders(&mut self, enabled: bool) -> &mut Self {
+        self.validate_orders = enabled;
+        self
+    }
+
+    /// Whether orders are validated before being placed.
+    pub fn validates_orders(&self) -> bool {
+        self.validate_orders
+    }
+
+    /// Read symbol rules from `cache` instead of fetching them for each validated order.
+    /// Symbols missing from the cache are still fetched.
+    pub fn set_symbol_cache(&mut self, cache: SymbolCache) -> &mut Self {
+        self.symbol_cache = Some(cache);
+        self
+    }
+
     /// Redefine credentials.
+    /// The fee cache is reset since fee rates depend on the account.
     pub fn set_credentials(self: &mut Self, credentials: Credentials) -> &mut Self {
         self.credentials = credentials;
+        self.fee_cache = Arc::new(RwLock::new(HashMap::new()));
         self
     }
 
@@ -82,10 +152,70 @@ impl KuCoinClient {
         method: &str,
         payload: &str,
         endpoint: &str,
+    ) -> Result<T, reqwest::Error> {
+        self.send_to(&self.base_link, method, payload, endpoint)
+            .await
+    }
+
+    /// Send a request that changes account state, honouring dry-run mode.
+    ///
+    /// # Parameters
+    /// - method    : HTTP-request method.
+    /// - payload   : Body for HTTP-request.
+    /// - endpoint  : Service endpoint
+    /// - synthetic : Builds the result returned instead of the response in dry-run mode.
+    pub(crate) async fn send_mutation<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        payload: &str,
+        endpoint: &str,
+        synthetic: impl FnOnce() -> T,
+    ) -> Result<T, reqwest::Error> {
+        if !self.dry_run {
+            return self.send(method, payload, endpoint).await;
+        }
+
+        // Sign exactly as a real request would be, so signing issues still surface.
+        let headers = self.get_headers(payload, method, endpoint);
+        let sign = headers
+            .as_ref()
+            .ok()
+            .and_then(|h| h.get("KC-API-SIGN"))
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        log::info!(
+            "dry-run {} {}{} sign={}",
+            method,
+            self.base_link,
+            endpoint,
+            sign
+        );
+        log::debug!("dry-run body={}", redact(payload));
+        Ok(synthetic())
+    }
+
+    /// Same as `send`, against the futures host (`futures_link`).
+    pub async fn send_futures<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        payload: &str,
+        endpoint: &str,
+    ) -> Result<T, reqwest::Error> {
+        self.send_to(&self.futures_link, method, payload, endpoint)
+            .await
+    }
+
+    async fn send_to<T: DeserializeOwned>(
+        &self,
+        host: &str,
+        method: &str,
+        payload: &str,
+        endpoint: &str,
     ) -> Result<T, reqwest::Error> {
         let headers = self.get_headers(payload, method, endpoint);
         let method_type = Method::from_str(method).unwrap();
-        let url = format!("{}{}", self.base_link, endpoint);
+        let url = format!("{}{}", host, endpoint);
 
         // Build Dyn Request based on the method_type.
         let response = self
@@ -99,6 +229,76 @@ impl KuCoinClient {
         response.json::<T>().await
     }
 
+    /// Check that the configured API key can serve the given handlers.
+    ///
+    /// # Parameters
+    /// - scopes : The handlers the caller intends to use.
+    ///
+    /// # Returns
+    /// * `Ok(ApiKeyInfo)` - The key information, when every required permission is granted.
+    /// * `Err(KucoinErrors::MissingPermissions)` - The permissions the key lacks.
+    pub async fn verify(&self, scopes: &[Scope]) -> KucoinResults<ApiKeyInfo> {
+        let info = self.account().api_key_info().await?.into_data()?;
+        let granted = info.permissions();
+
+        let mut missing: Vec<Permission> = Vec::new();
+        for scope in scopes {
+            let permission = scope.required_permission();
+            if !granted.contains(&permission) && !missing.contains(&permission) {
+                missing.push(permission);
+            }
+        }
+
+        if missing.is_empty() {
+            Ok(info)
+        } else {
+            Err(KucoinErrors::MissingPermissions(missing))
+        }
+    }
+
+    /// Keep Dead Cancel Protection armed while this process is alive.
+    ///
+    /// Re-sends `request` every `every`, starting immediately. If the process dies or hangs,
+    /// refreshes stop and the exchange cancels the protected orders once the timeout elapses.
+    /// `every` should be well below the timeout so a single failed refresh does not trigger it;
+    /// failures are logged at `warn` level and retried on the next tick. Abort the returned
+    /// handle, then send `DcpRequest::disable()`, to stand down cleanly.
+    ///
+    /// # Returns
+    /// * `Err(KucoinErrors::InvalidRequest)` - The timeout is out of range or `every` is zero;
+    ///   nothing is spawned.
+    pub fn spawn_dcp_keepalive(
+        &self,
+        request: DcpRequest,
+        every: Duration,
+    ) -> KucoinResults<JoinHandle<()>> {
+        request.build_body()?;
+        if every.is_zero() {
+            return Err(KucoinErrors::InvalidRequest(
+                "DCP refresh period must be positive".to_string(),
+            ));
+        }
+
+        let client = self.clone();
+        Ok(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(every);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = client.refresh_dcp(&request).await {
+                    log::warn!("DCP refresh failed: {:?}", e);
+                }
+            }
+        }))
+    }
+
+    /// Send one DCP refresh, turning an API rejection (non-success code) into an error.
+    async fn refresh_dcp(&self, request: &DcpRequest) -> KucoinResults<DcpData> {
+        self.spot()
+            .set_dcp(request.clone())
+            .await
+            .and_then(|r| r.into_data())
+    }
+
     /// Build headers with generated encoded for KC-API-SIGN and KC-API-PASSPHRASE.
     ///
     /// # Parameters
@@ -150,10 +350,30 @@ impl KuCoinClient {
 
     // --- Modular Accessors ---
 
+    pub fn account(&self) -> AccountHandler<'_> {
+        AccountHandler { client: self }
+    }
+
     pub fn deposit(&self) -> DepositHandler {
         DepositHandler { client: self }
     }
 
+    pub fn margin(&self) -> MarginHandler<'_> {
+        MarginHandler { client: self }
+    }
+
+    pub fn market(&self) -> MarketHandler<'_> {
+        MarketHandler { client: self }
+    }
+
+    pub fn oco(&self) -> OcoHandler<'_> {
+        OcoHandler { client: self }
+    }
+
+    pub fn portfolio(&self) -> PortfolioHandler<'_> {
+        PortfolioHandler { client: self }
+    }
+
     pub fn spot(&self) -> SpotHandler {
         SpotHandler { client: self }
     }
@@ -170,3 +390,68 @@ impl KuCoinClient {
         WithdrawHandler { client: self }
     }
 }
+
+/// Placeholder for secrets in logs and dry-run results.
+pub(crate) const REDACTED: &str = "***";
+
+/// A JSON body with the values of secret-looking fields (passphrase, secret, password)
+/// replaced by `REDACTED`, for logging. Non-JSON bodies are returned unchanged.
+fn redact(payload: &str) -> String {
+    fn mask(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    let key = key.to_ascii_lowercase();
+                    if ["passphrase", "secret", "password"]
+                        .iter()
+                        .any(|word| key.contains(word))
+                    {
+                        *value = serde_json::Value::String(REDACTED.to_string());
+                    } else {
+                        mask(value);
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(mask),
+            _ => {}
+        }
+    }
+
+    match serde_json::from_str::<serde_json::Value>(payload) {
+        Ok(mut value) => {
+            mask(&mut value);
+            value.to_string()
+        }
+        Err(_) => payload.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::mock::MockServer;
+
+    #[tokio::test]
+    async fn test_dcp_refresh_rejected() {
+        let server = MockServer::start(|_| {
+            r#"{"code":"400007","msg":"Access denied, require more permission"}"#.to_string()
+        });
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client.base_link = server.url.clone();
+
+        let res = client.refresh_dcp(&DcpRequest::new(30)).await;
+        assert!(matches!(res, Err(KucoinErrors::ApiError { code, .. }) if code == "400007"));
+        assert_eq!(server.requests(), vec!["/api/v1/hf/orders/dead-cancel-all"]);
+    }
+
+    #[test]
+    fn test_redact() {
+        let body = r#"{"subName":"bot","passphrase":"hunter22","nested":[{"apiSecret":"s"}]}"#;
+        let redacted = redact(body);
+        assert!(!redacted.contains("hunter22"));
+        assert!(redacted.contains(r#""passphrase":"***""#));
+        assert!(redacted.contains(r#""apiSecret":"***""#));
+        assert!(redacted.contains(r#""subName":"bot""#));
+        assert_eq!(redact("symbol=BTC-USDT"), "symbol=BTC-USDT");
+    }
+}
@@ -0,0 +1,259 @@
+use std::{collections::HashSet, time::Duration};
+
+use tokio::{sync::mpsc, task::JoinHandle};
+
+use crate::{
+    client::{
+        cache::{SymbolCache, SymbolEvent},
+        rest::KuCoinClient,
+    },
+    types::market::{Announcement, AnnouncementRequest, AnnouncementType, SymbolInfo},
+    utils::errors::KucoinResults,
+};
+
+/// A listing-related change, either announced or observed in the symbol list.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListingEvent {
+    /// A new `new-listings` announcement was published.
+    ListingAnnounced(Announcement),
+    /// A new `delistings` announcement was published.
+    DelistingAnnounced(Announcement),
+    /// A symbol appeared in the symbol list.
+    SymbolListed(SymbolInfo),
+    /// A listed symbol switched `enableTrading` on.
+    TradingEnabled(SymbolInfo),
+    /// A symbol disappeared from the symbol list.
+    SymbolDelisted(SymbolInfo),
+}
+
+/// Watches announcements and the symbol list for listings and delistings.
+///
+/// The first batch of announcements and the first symbol snapshot only set the baseline, later
+/// batches emit events for what is new. The `ingest_*` methods take already-fetched data, so the
+/// watcher can be driven offline from recorded responses.
+pub struct ListingWatcher {
+    seen: HashSet<i64>,
+    primed: bool,
+    symbols: SymbolCache,
+    lang: Option<String>,
+}
+
+impl Default for ListingWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ListingWatcher {
+    pub fn new() -> Self {
+        ListingWatcher {
+            seen: HashSet::new(),
+            primed: false,
+            symbols: SymbolCache::new(),
+            lang: None,
+        }
+    }
+
+    /// Language of the announcements to poll, e.g. "en_US" (Chainable).
+    pub fn set_lang(mut self, lang: &str) -> Self {
+        self.lang = Some(lang.to_string());
+        self
+    }
+
+    /// Feed a batch of announcements, returning listing/delisting announcements not seen before.
+    pub fn ingest_announcements(&mut self, items: Vec<Announcement>) -> Vec<ListingEvent> {
+        let mut events = Vec::new();
+        for item in items {
+            if !self.seen.insert(item.ann_id) || !self.primed {
+                continue;
+            }
+            if item.has_type(AnnouncementType::NewListings) {
+                events.push(ListingEvent::ListingAnnounced(item));
+            } else if item.has_type(AnnouncementType::Delistings) {
+                events.push(ListingEvent::DelistingAnnounced(item));
+            }
+        }
+        self.primed = true;
+        events
+    }
+
+    /// Feed a snapshot of the symbol list, returning the listings and delistings it reveals.
+    pub fn ingest_symbols(&mut self, symbols: Vec<SymbolInfo>) -> Vec<ListingEvent> {
+        self.symbols
+            .update(symbols)
+            .into_iter()
+            .filter_map(|event| match event {
+                SymbolEvent::Added(info) => Some(ListingEvent::SymbolListed(info)),
+                SymbolEvent::Removed(info) => Some(ListingEvent::SymbolDelisted(info)),
+                SymbolEvent::Changed { old, new } if !old.enable_trading && new.enable_trading => {
+                    Some(ListingEvent::TradingEnabled(*new))
+                }
+                SymbolEvent::Changed { .. } => None,
+            })
+            .collect()
+    }
+
+    /// Fetch the latest listing/delisting announcements and the symbol list once.
+    ///
+    /// Both sources are fetched before either is ingested, so a failed request leaves the
+    /// watcher untouched and the same events are emitted by the next successful poll.
+    pub async fn poll(&mut self, client: &KuCoinClient) -> KucoinResults<Vec<ListingEvent>> {
+        let mut items = Vec::new();
+        for ann_type in [AnnouncementType::NewListings, AnnouncementType::Delistings] {
+            let mut filter = AnnouncementRequest::new().set_ann_type(ann_type);
+            if let Some(lang) = &self.lang {
+                filter = filter.set_lang(lang);
+            }
+            items.extend(
+                client
+                    .market()
+                    .announcements(filter)
+                    .await?
+                    .into_data()?
+                    .items,
+            );
+        }
+        let symbols = client.market().symbols(None).await?.into_data()?;
+
+        let mut events = self.ingest_announcements(items);
+        events.extend(self.ingest_symbols(symbols));
+        Ok(events)
+    }
+
+    /// Poll every `every` in a background task, sending events to the returned receiver.
+    /// Failed polls are skipped; the task ends once the receiver is dropped.
+    pub fn spawn(
+        mut self,
+        client: KuCoinClient,
+        every: Duration,
+    ) -> (JoinHandle<()>, mpsc::UnboundedReceiver<ListingEvent>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(every);
+            loop {
+                ticker.tick().await;
+                let Ok(events) = self.poll(&client).await else {
+                    continue;
+                };
+                for event in events {
+                    if tx.send(event).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+        (handle, rx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+    use crate::{
+        client::{fixtures::symbol_info, mock::MockServer, rest::Credentials},
+        types::{KuCoinResponse, market::AnnouncementList},
+    };
+
+    const FIRST_PAGE: &str = r#"{"code":"200000","data":{"totalNum":2,"currentPage":1,"pageSize":50,"totalPage":1,"items":[
+        {"annId":101,"annTitle":"KuCoin Gets Listed AAA","annType":["latest-announcements","new-listings"],"annDesc":"AAA","cTime":1729594043000,"language":"en_US","annUrl":"https://www.kucoin.com/announcement/aaa"},
+        {"annId":100,"annTitle":"Maintenance","annType":["maintenance-updates"],"annDesc":"","cTime":1729590000000,"language":"en_US","annUrl":"https://www.kucoin.com/announcement/m"}
+    ]}}"#;
+
+    const SECOND_PAGE: &str = r#"{"code":"200000","data":{"totalNum":3,"currentPage":1,"pageSize":50,"totalPage":1,"items":[
+        {"annId":103,"annTitle":"KuCoin Will Delist BBB","annType":["delistings"],"annDesc":"BBB","cTime":1729680000000,"language":"en_US","annUrl":"https://www.kucoin.com/announcement/bbb"},
+        {"annId":102,"annTitle":"KuCoin Gets Listed CCC","annType":["new-listings","some-future-type"],"annDesc":"CCC","cTime":1729670000000,"language":"en_US","annUrl":"https://www.kucoin.com/announcement/ccc"},
+        {"annId":101,"annTitle":"KuCoin Gets Listed AAA","annType":["latest-announcements","new-listings"],"annDesc":"AAA","cTime":1729594043000,"language":"en_US","annUrl":"https://www.kucoin.com/announcement/aaa"}
+    ]}}"#;
+
+    fn items(json: &str) -> Vec<Announcement> {
+        let res: KuCoinResponse<AnnouncementList> = serde_json::from_str(json).unwrap();
+        res.into_data().unwrap().items
+    }
+
+    fn symbol(code: &str, enable_trading: bool) -> SymbolInfo {
+        symbol_info(code, "0.0001", enable_trading)
+    }
+
+    #[test]
+    fn test_announcement_events() {
+        let mut watcher = ListingWatcher::new();
+
+        assert!(watcher.ingest_announcements(items(FIRST_PAGE)).is_empty());
+
+        let events = watcher.ingest_announcements(items(SECOND_PAGE));
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], ListingEvent::DelistingAnnounced(a) if a.ann_id == 103));
+        assert!(matches!(&events[1], ListingEvent::ListingAnnounced(a) if a.ann_id == 102));
+
+        // Replaying the same page emits nothing.
+        assert!(watcher.ingest_announcements(items(SECOND_PAGE)).is_empty());
+    }
+
+    #[test]
+    fn test_symbol_events() {
+        let mut watcher = ListingWatcher::new();
+
+        assert!(
+            watcher
+                .ingest_symbols(vec![symbol("AAA-USDT", true), symbol("BBB-USDT", true)])
+                .is_empty()
+        );
+
+        let events =
+            watcher.ingest_symbols(vec![symbol("AAA-USDT", true), symbol("CCC-USDT", false)]);
+        assert_eq!(
+            events,
+            vec![
+                ListingEvent::SymbolDelisted(symbol("BBB-USDT", true)),
+                ListingEvent::SymbolListed(symbol("CCC-USDT", false)),
+            ]
+        );
+
+        let events =
+            watcher.ingest_symbols(vec![symbol("AAA-USDT", true), symbol("CCC-USDT", true)]);
+        assert_eq!(
+            events,
+            vec![ListingEvent::TradingEnabled(symbol("CCC-USDT", true))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_keeps_events_when_symbols_fail() {
+        let symbol_calls = Arc::new(AtomicUsize::new(0));
+        let calls = symbol_calls.clone();
+        let announcements = Arc::new(AtomicUsize::new(0));
+        let pages = announcements.clone();
+        let server = MockServer::start(move |target| {
+            if target.starts_with("/api/v3/announcements") {
+                // Two calls (listings, delistings) per poll; the baseline poll gets FIRST_PAGE.
+                let page = if pages.fetch_add(1, Ordering::SeqCst) < 2 {
+                    FIRST_PAGE
+                } else {
+                    SECOND_PAGE
+                };
+                return page.to_string();
+            }
+            if calls.fetch_add(1, Ordering::SeqCst) == 1 {
+                return r#"{"code":"500000","msg":"busy"}"#.to_string();
+            }
+            let symbol = serde_json::to_string(&symbol("AAA-USDT", true)).unwrap();
+            format!(r#"{{"code":"200000","data":[{}]}}"#, symbol)
+        });
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client.base_link = server.url.clone();
+
+        let mut watcher = ListingWatcher::new();
+        assert!(watcher.poll(&client).await.unwrap().is_empty());
+        assert!(watcher.poll(&client).await.is_err());
+
+        // The announcements fetched by the failed poll are reported by the next one.
+        let events = watcher.poll(&client).await.unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], ListingEvent::DelistingAnnounced(a) if a.ann_id == 103));
+    }
+}
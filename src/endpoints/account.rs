@@ -0,0 +1,530 @@
+use futures::{Stream, StreamExt, TryStreamExt, stream};
+
+use crate::{
+    client::rest::KuCoinClient,
+    types::{
+        KuCoinResponse,
+        account::{
+            AccountBalance, AccountDetail, AccountSummary, ApiKeyInfo, BaseFee, BizType,
+            CurrencyType, Direction, FuturesAccount, HfLedger, HfLedgerRequest, Ledger, LedgerList,
+            LedgerRequest, TradeFee,
+        },
+        transfer::AccountType,
+    },
+    utils::errors::{KucoinErrors, KucoinResults},
+};
+
+/// Default page size of the HF ledgers endpoint.
+const HF_LEDGER_LIMIT: i64 = 100;
+/// Server maximum of records per HF ledger request.
+const HF_LEDGER_MAX_LIMIT: i64 = 200;
+
+/// Maximum number of symbols per trade-fees request.
+const TRADE_FEE_CHUNK: usize = 10;
+/// Trade-fees requests in flight at once.
+const TRADE_FEE_CONCURRENCY: usize = 4;
+
+pub struct AccountHandler<'a> {
+    pub client: &'a KuCoinClient,
+}
+
+impl LedgerRequest {
+    /// Create a ledger query, all filters unset (last 24 hours of every currency).
+    pub fn new() -> Self {
+        LedgerRequest {
+            currency: None,
+            direction: None,
+            biz_type: None,
+            start_at: None,
+            end_at: None,
+            current_page: None,
+            page_size: None,
+        }
+    }
+
+    /// Filter by currency, up to 10 comma-separated currencies (Chainable).
+    pub fn set_currency(mut self, currency: &str) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    /// Filter by direction (Chainable).
+    pub fn set_direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Filter by business type (Chainable).
+    pub fn set_biz_type(mut self, biz_type: BizType) -> Self {
+        self.biz_type = Some(biz_type);
+        self
+    }
+
+    /// Set the start timestamp in milliseconds (Chainable).
+    pub fn set_start_at(mut self, start_at: i64) -> Self {
+        self.start_at = Some(start_at);
+        self
+    }
+
+    /// Set the end timestamp in milliseconds (Chainable).
+    pub fn set_end_at(mut self, end_at: i64) -> Self {
+        self.end_at = Some(end_at);
+        self
+    }
+
+    /// Set the current page (Chainable).
+    pub fn set_current_page(mut self, page: i64) -> Self {
+        self.current_page = Some(page);
+        self
+    }
+
+    /// Set the page size (Chainable).
+    /// Note: API requires min 10, max 500.
+    pub fn set_page_size(mut self, size: i64) -> Self {
+        self.page_size = Some(size);
+        self
+    }
+}
+
+impl Default for LedgerRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HfLedgerRequest {
+    /// Create an HF ledger query, all filters unset (last 7 days of every currency).
+    pub fn new() -> Self {
+        HfLedgerRequest {
+            currency: None,
+            direction: None,
+            biz_type: None,
+            last_id: None,
+            limit: None,
+            start_at: None,
+            end_at: None,
+        }
+    }
+
+    /// Filter by currency, up to 10 comma-separated currencies (Chainable).
+    pub fn set_currency(mut self, currency: &str) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    /// Filter by direction (Chainable).
+    pub fn set_direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Filter by business type (Chainable).
+    pub fn set_biz_type(mut self, biz_type: BizType) -> Self {
+        self.biz_type = Some(biz_type);
+        self
+    }
+
+    /// Continue after the record with this id (Chainable).
+    pub fn set_last_id(mut self, last_id: &str) -> Self {
+        self.last_id = Some(last_id.to_string());
+        self
+    }
+
+    /// Set the number of records per request (Chainable).
+    /// Note: API default 100, max 200; larger values are clamped to 200.
+    pub fn set_limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit.clamp(1, HF_LEDGER_MAX_LIMIT));
+        self
+    }
+
+    /// Set the start timestamp in milliseconds (Chainable).
+    pub fn set_start_at(mut self, start_at: i64) -> Self {
+        self.start_at = Some(start_at);
+        self
+    }
+
+    /// Set the end timestamp in milliseconds (Chainable).
+    pub fn set_end_at(mut self, end_at: i64) -> Self {
+        self.end_at = Some(end_at);
+        self
+    }
+}
+
+impl Default for HfLedgerRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> AccountHandler<'a> {
+    /// Get the list of accounts (balances) of the current user.
+    ///
+    /// # Arguments
+    /// * `currency` - Restrict to a currency (e.g., "USDT"), `None` for all.
+    /// * `account_type` - Restrict to `Main`, `Trade` or `Margin`, `None` for all.
+    pub async fn list(
+        &self,
+        currency: Option<&str>,
+        account_type: Option<AccountType>,
+    ) -> KucoinResults<KuCoinResponse<Vec<AccountBalance>>> {
+        let mut query = Vec::new();
+        if let Some(currency) = currency {
+            query.push(("currency", currency));
+        }
+        if let Some(account_type) = &account_type {
+            query.push(("type", account_type.as_query()));
+        }
+
+        let endpoint = if query.is_empty() {
+            "/api/v1/accounts".to_string()
+        } else {
+            format!("/api/v1/accounts?{}", serde_urlencoded::to_string(&query)?)
+        };
+        let res = self
+            .client
+            .send::<KuCoinResponse<Vec<AccountBalance>>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get the balance of a single account by its ID (see `AccountBalance::id`).
+    pub async fn detail(&self, account_id: &str) -> KucoinResults<KuCoinResponse<AccountDetail>> {
+        let endpoint = format!("/api/v1/accounts/{}", account_id);
+        let res = self
+            .client
+            .send::<KuCoinResponse<AccountDetail>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get the futures account overview of a settlement currency.
+    ///
+    /// # Arguments
+    /// * `currency` - Settlement currency: "XBT" (BTC), "USDT" or "USDC", `None` for XBT.
+    pub async fn futures_overview(
+        &self,
+        currency: Option<&str>,
+    ) -> KucoinResults<KuCoinResponse<FuturesAccount>> {
+        let endpoint = match currency {
+            Some(currency) => format!("/api/v1/account-overview?currency={}", currency),
+            None => "/api/v1/account-overview".to_string(),
+        };
+        let res = self
+            .client
+            .send_futures::<KuCoinResponse<FuturesAccount>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get the account summary: VIP level, sub-account counts and limits.
+    pub async fn summary(&self) -> KucoinResults<KuCoinResponse<AccountSummary>> {
+        let endpoint = "/api/v2/user-info";
+        let res = self
+            .client
+            .send::<KuCoinResponse<AccountSummary>>("GET", "", endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get the information of the API key in use: permissions, IP whitelist, version...
+    pub async fn api_key_info(&self) -> KucoinResults<KuCoinResponse<ApiKeyInfo>> {
+        let endpoint = "/api/v1/user/api-key";
+        let res = self
+            .client
+            .send::<KuCoinResponse<ApiKeyInfo>>("GET", "", endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get the base fee rates of the account.
+    ///
+    /// # Arguments
+    /// * `currency_type` - Crypto or fiat, `None` for the API default (crypto).
+    pub async fn base_fee(
+        &self,
+        currency_type: Option<CurrencyType>,
+    ) -> KucoinResults<KuCoinResponse<BaseFee>> {
+        let endpoint = match currency_type {
+            Some(CurrencyType::Crypto) => "/api/v1/base-fee?currencyType=0",
+            Some(CurrencyType::Fiat) => "/api/v1/base-fee?currencyType=1",
+            None => "/api/v1/base-fee",
+        };
+        let res = self
+            .client
+            .send::<KuCoinResponse<BaseFee>>("GET", "", endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Fetch the actual fee rates of `symbols`, bypassing and refreshing the fee cache.
+    /// Symbols are split into requests of 10, a few of which are sent at a time.
+    pub async fn fetch_trade_fees(&self, symbols: &[&str]) -> KucoinResults<Vec<TradeFee>> {
+        let chunks: Vec<Vec<TradeFee>> = stream::iter(symbols.chunks(TRADE_FEE_CHUNK))
+            .map(|chunk| async move {
+                let endpoint = format!("/api/v1/trade-fees?symbols={}", chunk.join(","));
+                self.client
+                    .send::<KuCoinResponse<Vec<TradeFee>>>("GET", "", &endpoint)
+                    .await?
+                    .into_data()
+            })
+            .buffer_unordered(TRADE_FEE_CONCURRENCY)
+            .try_collect()
+            .await?;
+        let fees: Vec<TradeFee> = chunks.into_iter().flatten().collect();
+
+        let mut cache = self.client.fee_cache.write().unwrap();
+        for fee in &fees {
+            cache.insert(fee.symbol.clone(), fee.clone());
+        }
+        Ok(fees)
+    }
+
+    /// Actual fee rates of `symbols`, fetching only those missing from the fee cache.
+    pub async fn trade_fees(&self, symbols: &[&str]) -> KucoinResults<Vec<TradeFee>> {
+        let missing: Vec<&str> = {
+            let cache = self.client.fee_cache.read().unwrap();
+            symbols
+                .iter()
+                .filter(|symbol| !cache.contains_key(**symbol))
+                .copied()
+                .collect()
+        };
+        if !missing.is_empty() {
+            self.fetch_trade_fees(&missing).await?;
+        }
+
+        let cache = self.client.fee_cache.read().unwrap();
+        Ok(symbols
+            .iter()
+            .filter_map(|symbol| cache.get(*symbol).cloned())
+            .collect())
+    }
+
+    /// Actual fee rate of a single symbol, served from the fee cache when possible.
+    pub async fn trade_fee(&self, symbol: &str) -> KucoinResults<Option<TradeFee>> {
+        Ok(self.trade_fees(&[symbol]).await?.into_iter().next())
+    }
+
+    /// Get one page of the classic account ledgers.
+    pub async fn ledgers(
+        &self,
+        filter: LedgerRequest,
+    ) -> KucoinResults<KuCoinResponse<LedgerList>> {
+        let query = serde_urlencoded::to_string(&filter)?;
+        let endpoint = if query.is_empty() {
+            "/api/v1/accounts/ledgers".to_string()
+        } else {
+            format!("/api/v1/accounts/ledgers?{}", query)
+        };
+        let res = self
+            .client
+            .send::<KuCoinResponse<LedgerList>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Walk every page of the classic ledgers matching `filter`, starting at its current page.
+    pub fn ledgers_stream(
+        &self,
+        filter: LedgerRequest,
+    ) -> impl Stream<Item = KucoinResults<Ledger>> + 'a {
+        let client = self.client;
+        let first = filter.current_page.unwrap_or(1);
+
+        stream::try_unfold(
+            Some(filter.set_current_page(first)),
+            move |next| async move {
+                let Some(filter) = next else {
+                    return Ok::<_, KucoinErrors>(None);
+                };
+                let page = client
+                    .account()
+                    .ledgers(filter.clone())
+                    .await?
+                    .into_data()?;
+                let following = (page.current_page < page.total_page)
+                    .then(|| filter.set_current_page(page.current_page + 1));
+                Ok(Some((page.items, following)))
+            },
+        )
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// Get one page of the high-frequency trade account ledgers, newest first.
+    pub async fn hf_ledgers(
+        &self,
+        filter: HfLedgerRequest,
+    ) -> KucoinResults<KuCoinResponse<Vec<HfLedger>>> {
+        let query = serde_urlencoded::to_string(&filter)?;
+        let endpoint = if query.is_empty() {
+            "/api/v1/hf/accounts/ledgers".to_string()
+        } else {
+            format!("/api/v1/hf/accounts/ledgers?{}", query)
+        };
+        let res = self
+            .client
+            .send::<KuCoinResponse<Vec<HfLedger>>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Follow the `lastId` cursor through every HF ledger record matching `filter`.
+    pub fn hf_ledgers_stream(
+        &self,
+        filter: HfLedgerRequest,
+    ) -> impl Stream<Item = KucoinResults<HfLedger>> + 'a {
+        let client = self.client;
+        // A page shorter than the limit is the last one; the server caps pages at 200.
+        let limit = filter
+            .limit
+            .unwrap_or(HF_LEDGER_LIMIT)
+            .clamp(1, HF_LEDGER_MAX_LIMIT);
+
+        stream::try_unfold(Some(filter), move |next| async move {
+            let Some(filter) = next else {
+                return Ok::<_, KucoinErrors>(None);
+            };
+            let items = client
+                .account()
+                .hf_ledgers(filter.clone())
+                .await?
+                .into_data()?;
+            let following = match items.last() {
+                Some(last) if items.len() as i64 >= limit => Some(filter.set_last_id(&last.id)),
+                _ => None,
+            };
+            Ok(Some((items, following)))
+        })
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        client::{fixtures::amt, mock::MockServer, rest::Credentials},
+        types::account::{Permission, Scope},
+    };
+
+    #[test]
+    fn test_parse_balances() {
+        let json = r#"{"code":"200000","data":[
+            {"id":"548674591753","currency":"USDT","type":"trade","balance":"26.66759503","available":"26.66759503","holds":"0"},
+            {"id":"63355cd156298d0001b66e61","currency":"USDT","type":"main","balance":"0.01","available":"0.005","holds":"0.005"}
+        ]}"#;
+        let res: KuCoinResponse<Vec<AccountBalance>> = serde_json::from_str(json).unwrap();
+        let accounts = res.into_data().unwrap();
+
+        assert!(matches!(accounts[0].account_type, AccountType::Trade));
+        assert!(matches!(accounts[1].account_type, AccountType::Main));
+        assert_eq!(accounts[0].available, amt("26.66759503"));
+        assert_eq!(accounts[1].holds, amt("0.005"));
+    }
+
+    #[test]
+    fn test_api_key_permissions() {
+        let json = r#"{"code":"200000","data":{"remark":"account1","apiKey":"6705f5c311545b000157d3eb","apiVersion":3,"permission":"General,Futures,Spot,Earn,InnerTransfer,Transfer,Margin,SomethingNew","ipWhitelist":"203.**.154,103.**.34","createdAt":1728443843000,"uid":165111215,"isMaster":true}}"#;
+        let res: KuCoinResponse<ApiKeyInfo> = serde_json::from_str(json).unwrap();
+        let info = res.into_data().unwrap();
+
+        assert_eq!(info.permissions().len(), 8);
+        assert!(
+            info.permissions()
+                .contains(&Permission::Unknown("SomethingNew".to_string()))
+        );
+        assert!(info.has_permission(Permission::InnerTransfer));
+        assert!(!info.has_permission(Permission::Unified));
+        assert_eq!(Scope::Withdraw.required_permission(), Permission::Transfer);
+    }
+
+    #[test]
+    fn test_parse_trade_fees() {
+        let json = r#"{"code":"200000","data":[{"symbol":"BTC-USDT","takerFeeRate":"0.001","makerFeeRate":"0.0008"}]}"#;
+        let res: KuCoinResponse<Vec<TradeFee>> = serde_json::from_str(json).unwrap();
+        let fees = res.into_data().unwrap();
+
+        assert_eq!(fees[0].taker_fee_rate, amt("0.001"));
+        assert_eq!(fees[0].maker_fee_rate, amt("0.0008"));
+    }
+
+    #[tokio::test]
+    async fn test_trade_fees_chunked_and_cached() {
+        // Answer every requested symbol with the same rates.
+        let server = MockServer::start(|target| {
+            let symbols = target.split("symbols=").nth(1).unwrap_or_default();
+            let fees: Vec<String> = symbols
+                .split(',')
+                .map(|symbol| {
+                    format!(
+                        r#"{{"symbol":"{}","takerFeeRate":"0.001","makerFeeRate":"0.001"}}"#,
+                        symbol
+                    )
+                })
+                .collect();
+            format!(r#"{{"code":"200000","data":[{}]}}"#, fees.join(","))
+        });
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client.base_link = server.url.clone();
+
+        let symbols: Vec<String> = (0..30).map(|i| format!("S{}-USDT", i)).collect();
+        let symbols: Vec<&str> = symbols.iter().map(String::as_str).collect();
+
+        let fees = client.account().trade_fees(&symbols[..25]).await.unwrap();
+        assert_eq!(fees.len(), 25);
+        assert_eq!(server.requests().len(), 3);
+
+        // Only the 5 symbols missing from the cache are fetched.
+        let fees = client.account().trade_fees(&symbols[20..]).await.unwrap();
+        assert_eq!(fees.len(), 10);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(
+            requests[3],
+            "/api/v1/trade-fees?symbols=S25-USDT,S26-USDT,S27-USDT,S28-USDT,S29-USDT"
+        );
+    }
+
+    #[test]
+    fn test_ledger_query() {
+        let filter = HfLedgerRequest::new()
+            .set_currency("BTC,USDT")
+            .set_biz_type(BizType::TradeExchange)
+            .set_direction(Direction::Out)
+            .set_last_id("254062248624417");
+        let query = serde_urlencoded::to_string(&filter).unwrap();
+
+        assert_eq!(
+            query,
+            "currency=BTC%2CUSDT&direction=out&bizType=TRADE_EXCHANGE&lastId=254062248624417"
+        );
+    }
+
+    #[test]
+    fn test_parse_hf_ledgers() {
+        let json = r#"{"code":"200000","data":[
+            {"id":"254062248624417","currency":"USDT","amount":"1.59760080","fee":"0.00159920","tax":"0","balance":"26.73759503","accountType":"TRADE_HF","bizType":"TRADE_EXCHANGE","direction":"in","createdAt":"1728443957539","context":"{\"symbol\":\"KCS-USDT\"}"}
+        ]}"#;
+        let res: KuCoinResponse<Vec<HfLedger>> = serde_json::from_str(json).unwrap();
+        let items = res.into_data().unwrap();
+
+        assert_eq!(items[0].created_at, 1728443957539);
+        assert_eq!(items[0].direction, Direction::In);
+        assert_eq!(items[0].biz_type, BizType::TradeExchange);
+    }
+
+    #[test]
+    fn test_parse_ledger_unknown_biz_type() {
+        let json = r#"{"id":"611a1e7c6a053300067a88d9","currency":"USDT","amount":"10.00059547","fee":"0","balance":"0","accountType":"MAIN","bizType":"REFERRAL_BONUS","direction":"in","createdAt":1629101692950,"context":null}"#;
+        let ledger: Ledger = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            ledger.biz_type,
+            BizType::Unknown("REFERRAL_BONUS".to_string())
+        );
+
+        let filter = HfLedgerRequest::new().set_limit(500);
+        assert_eq!(filter.limit, Some(200));
+    }
+}
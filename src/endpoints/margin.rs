@@ -0,0 +1,149 @@
+use crate::{
+    client::rest::KuCoinClient,
+    types::{
+        KuCoinResponse,
+        margin::{CrossMarginAccount, IsolatedMarginAccount},
+        transfer::AccountType,
+    },
+    utils::errors::{KucoinErrors, KucoinResults},
+};
+
+pub struct MarginHandler<'a> {
+    pub client: &'a KuCoinClient,
+}
+
+impl<'a> MarginHandler<'a> {
+    /// Get the cross margin account.
+    ///
+    /// # Arguments
+    /// * `quote_currency` - Currency the totals are valued in: "USDT" (default), "KCS" or "BTC".
+    /// * `query_type` - `Margin` or `MarginV2`, `None` for both. Other account types are
+    ///   rejected with `InvalidRequest`.
+    pub async fn cross_account(
+        &self,
+        quote_currency: Option<&str>,
+        query_type: Option<AccountType>,
+    ) -> KucoinResults<KuCoinResponse<CrossMarginAccount>> {
+        let query = serde_urlencoded::to_string([
+            ("quoteCurrency", quote_currency.unwrap_or("USDT")),
+            ("queryType", query_type_value(query_type.as_ref(), false)?),
+        ])?;
+        let endpoint = format!("/api/v3/margin/accounts?{}", query);
+        let res = self
+            .client
+            .send::<KuCoinResponse<CrossMarginAccount>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get the isolated margin accounts.
+    ///
+    /// # Arguments
+    /// * `symbol` - Restrict to a trading pair (e.g., "BTC-USDT"), `None` for all.
+    /// * `quote_currency` - Currency the totals are valued in: "USDT" (default), "KCS" or "BTC".
+    /// * `query_type` - `Isolated` or `IsolatedV2`, `None` for both. Other account types are
+    ///   rejected with `InvalidRequest`.
+    pub async fn isolated_accounts(
+        &self,
+        symbol: Option<&str>,
+        quote_currency: Option<&str>,
+        query_type: Option<AccountType>,
+    ) -> KucoinResults<KuCoinResponse<IsolatedMarginAccount>> {
+        let mut query = vec![
+            ("quoteCurrency", quote_currency.unwrap_or("USDT")),
+            ("queryType", query_type_value(query_type.as_ref(), true)?),
+        ];
+        if let Some(symbol) = symbol {
+            query.push(("symbol", symbol));
+        }
+
+        let endpoint = format!(
+            "/api/v3/isolated/accounts?{}",
+            serde_urlencoded::to_string(&query)?
+        );
+        let res = self
+            .client
+            .send::<KuCoinResponse<IsolatedMarginAccount>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+}
+
+/// The `queryType` parameter of the cross (`isolated == false`) or isolated margin accounts,
+/// "ALL" when unset. An account type the endpoint does not serve is an error.
+fn query_type_value(
+    query_type: Option<&AccountType>,
+    isolated: bool,
+) -> KucoinResults<&'static str> {
+    match (query_type, isolated) {
+        (None, _) => Ok("ALL"),
+        (Some(AccountType::Margin), false) => Ok("MARGIN"),
+        (Some(AccountType::MarginV2), false) => Ok("MARGIN_V2"),
+        (Some(AccountType::Isolated), true) => Ok("ISOLATED"),
+        (Some(AccountType::IsolatedV2), true) => Ok("ISOLATED_V2"),
+        (Some(other), _) => Err(KucoinErrors::InvalidRequest(format!(
+            "queryType {:?} is not valid for the {} margin accounts",
+            other,
+            if isolated { "isolated" } else { "cross" }
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{client::fixtures::amt, types::margin::MarginStatus};
+
+    #[test]
+    fn test_parse_isolated_accounts() {
+        let json = r#"{"code":"200000","data":{"totalAssetOfQuoteCurrency":"4.97047372","totalLiabilityOfQuoteCurrency":"0.00038891","timestamp":1747303659773,"assets":[
+            {"symbol":"BTC-USDT","status":"EFFECTIVE","debtRatio":"0.85",
+             "baseAsset":{"currency":"BTC","borrowEnabled":true,"transferInEnabled":true,"liability":"0","liabilityPrincipal":"0","liabilityInterest":"0","total":"0","available":"0","hold":"0","maxBorrowSize":"0"},
+             "quoteAsset":{"currency":"USDT","borrowEnabled":true,"transferInEnabled":true,"liability":"0.00038891","liabilityPrincipal":"0.00038888","liabilityInterest":"0.00000003","total":"4.97047372","available":"4.97047372","hold":"0","maxBorrowSize":"44"}}
+        ]}}"#;
+        let res: KuCoinResponse<IsolatedMarginAccount> = serde_json::from_str(json).unwrap();
+        let account = res.into_data().unwrap();
+
+        assert_eq!(account.assets[0].status, MarginStatus::Effective);
+        assert_eq!(
+            account.assets[0].quote_asset.liability_interest,
+            Some(amt("0.00000003"))
+        );
+        assert_eq!(account.at_risk(0.8).len(), 1);
+        assert!(account.at_risk(0.9).is_empty());
+    }
+
+    #[test]
+    fn test_query_type_value() {
+        assert_eq!(query_type_value(None, false).unwrap(), "ALL");
+        assert_eq!(
+            query_type_value(Some(&AccountType::MarginV2), false).unwrap(),
+            "MARGIN_V2"
+        );
+        assert_eq!(
+            query_type_value(Some(&AccountType::Isolated), true).unwrap(),
+            "ISOLATED"
+        );
+        assert!(matches!(
+            query_type_value(Some(&AccountType::Main), false),
+            Err(KucoinErrors::InvalidRequest(_))
+        ));
+        assert!(matches!(
+            query_type_value(Some(&AccountType::Margin), true),
+            Err(KucoinErrors::InvalidRequest(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_cross_account() {
+        let json = r#"{"code":"200000","data":{"totalAssetOfQuoteCurrency":"40.8648372","totalLiabilityOfQuoteCurrency":"0.5","debtRatio":"0.0122","status":"EFFECTIVE","accounts":[
+            {"currency":"USDT","total":"38.68855864","available":"20.01916691","hold":"0","liability":"0.5","maxBorrowSize":"163","borrowEnabled":true,"transferInEnabled":true}
+        ]}}"#;
+        let res: KuCoinResponse<CrossMarginAccount> = serde_json::from_str(json).unwrap();
+        let account = res.into_data().unwrap();
+
+        assert_eq!(account.debt_ratio, amt("0.0122"));
+        assert_eq!(account.borrowed().len(), 1);
+        assert_eq!(account.accounts[0].liability_principal, None);
+    }
+}
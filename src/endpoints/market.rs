@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+
+use crate::{
+    client::rest::KuCoinClient,
+    types::{
+        KuCoinResponse,
+        market::{
+            AllTickers, AnnouncementList, AnnouncementRequest, AnnouncementType, ChainInfo,
+            Currency, ServiceStatus, ServiceStatusData, SymbolInfo,
+        },
+    },
+    utils::{errors::KucoinResults, num::Amount},
+};
+
+impl AnnouncementRequest {
+    /// Create an announcement query, all filters unset.
+    pub fn new() -> Self {
+        AnnouncementRequest {
+            ann_type: None,
+            lang: None,
+            start_time: None,
+            end_time: None,
+            current_page: None,
+            page_size: None,
+        }
+    }
+
+    /// Filter by announcement type (Chainable).
+    pub fn set_ann_type(mut self, ann_type: AnnouncementType) -> Self {
+        self.ann_type = Some(ann_type);
+        self
+    }
+
+    /// Set the language, e.g. "en_US", "zh_HK" (Chainable).
+    pub fn set_lang(mut self, lang: &str) -> Self {
+        self.lang = Some(lang.to_string());
+        self
+    }
+
+    /// Set the start timestamp in milliseconds (Chainable).
+    pub fn set_start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Set the end timestamp in milliseconds (Chainable).
+    pub fn set_end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Set the current page (Chainable).
+    pub fn set_current_page(mut self, page: i64) -> Self {
+        self.current_page = Some(page);
+        self
+    }
+
+    /// Set the page size (Chainable).
+    pub fn set_page_size(mut self, size: i64) -> Self {
+        self.page_size = Some(size);
+        self
+    }
+}
+
+impl Default for AnnouncementRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct MarketHandler<'a> {
+    pub client: &'a KuCoinClient,
+}
+
+impl<'a> MarketHandler<'a> {
+    /// List all currencies with their chain (deposit/withdrawal) parameters.
+    pub async fn currencies(&self) -> KucoinResults<KuCoinResponse<Vec<Currency>>> {
+        let endpoint = "/api/v3/currencies";
+        let res = self
+            .client
+            .send::<KuCoinResponse<Vec<Currency>>>("GET", "", endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get the details of a single currency.
+    ///
+    /// # Arguments
+    /// * `currency` - Currency code (e.g., "USDT").
+    /// * `chain` - Restrict the returned chains to a single chain id (e.g., "trx"), `None` for all.
+    pub async fn currency(
+        &self,
+        currency: &str,
+        chain: Option<&str>,
+    ) -> KucoinResults<KuCoinResponse<Currency>> {
+        let endpoint = match chain {
+            Some(chain) => format!("/api/v3/currencies/{}?chain={}", currency, chain),
+            None => format!("/api/v3/currencies/{}", currency),
+        };
+        let res = self
+            .client
+            .send::<KuCoinResponse<Currency>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// List the chains of a currency on which both deposits and withdrawals are enabled.
+    pub async fn enabled_chains(&self, currency: &str) -> KucoinResults<Vec<ChainInfo>> {
+        let res = self.currency(currency, None).await?;
+        let chains = res
+            .data
+            .map(|data| data.enabled_chains().into_iter().cloned().collect())
+            .unwrap_or_default();
+        Ok(chains)
+    }
+
+    /// Get the fiat price of currencies.
+    ///
+    /// # Arguments
+    /// * `base` - Fiat currency the prices are quoted in (e.g., "USD", "EUR"), default is USD.
+    /// * `currencies` - Currencies to convert (e.g., ["BTC", "ETH"]), empty for all.
+    ///
+    /// # Returns
+    /// * Map of currency to its price in `base`.
+    pub async fn prices(
+        &self,
+        base: Option<&str>,
+        currencies: &[&str],
+    ) -> KucoinResults<KuCoinResponse<HashMap<String, Amount>>> {
+        let mut query = Vec::new();
+        if let Some(base) = base {
+            query.push(("base", base.to_string()));
+        }
+        if !currencies.is_empty() {
+            query.push(("currencies", currencies.join(",")));
+        }
+
+        let endpoint = if query.is_empty() {
+            "/api/v1/prices".to_string()
+        } else {
+            format!("/api/v1/prices?{}", serde_urlencoded::to_string(&query)?)
+        };
+        let res = self
+            .client
+            .send::<KuCoinResponse<HashMap<String, Amount>>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get the service status (open, close or cancelonly).
+    pub async fn status(&self) -> KucoinResults<KuCoinResponse<ServiceStatusData>> {
+        let endpoint = "/api/v1/status";
+        let res = self
+            .client
+            .send::<KuCoinResponse<ServiceStatusData>>("GET", "", endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Current service status, `None` if the response carried no data.
+    /// Order paths can check `ServiceStatus::can_place_orders` before submitting.
+    pub async fn service_status(&self) -> KucoinResults<Option<ServiceStatus>> {
+        let res = self.status().await?;
+        Ok(res.data.map(|data| data.status))
+    }
+
+    /// List all trading pairs with their trading rules.
+    ///
+    /// # Arguments
+    /// * `market` - Restrict to a trading market (e.g., "USDS", "BTC", "ALTS"), `None` for all.
+    pub async fn symbols(
+        &self,
+        market: Option<&str>,
+    ) -> KucoinResults<KuCoinResponse<Vec<SymbolInfo>>> {
+        let endpoint = match market {
+            Some(market) => format!("/api/v2/symbols?market={}", market),
+            None => "/api/v2/symbols".to_string(),
+        };
+        let res = self
+            .client
+            .send::<KuCoinResponse<Vec<SymbolInfo>>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get the trading rules of a single symbol (e.g., "BTC-USDT").
+    pub async fn symbol(&self, symbol: &str) -> KucoinResults<KuCoinResponse<SymbolInfo>> {
+        let endpoint = format!("/api/v2/symbols/{}", symbol);
+        let res = self
+            .client
+            .send::<KuCoinResponse<SymbolInfo>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get the 24h ticker of every trading pair.
+    pub async fn all_tickers(&self) -> KucoinResults<KuCoinResponse<AllTickers>> {
+        let endpoint = "/api/v1/market/allTickers";
+        let res = self
+            .client
+            .send::<KuCoinResponse<AllTickers>>("GET", "", endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get the latest announcements (listings, delistings, maintenance...).
+    pub async fn announcements(
+        &self,
+        filter: AnnouncementRequest,
+    ) -> KucoinResults<KuCoinResponse<AnnouncementList>> {
+        let query = serde_urlencoded::to_string(&filter)?;
+        let endpoint = if query.is_empty() {
+            "/api/v3/announcements".to_string()
+        } else {
+            format!("/api/v3/announcements?{}", query)
+        };
+        let res = self
+            .client
+            .send::<KuCoinResponse<AnnouncementList>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_enabled_chains() {
+        let json = r#"{
+            "currency": "USDT",
+            "name": "USDT",
+            "fullName": "Tether",
+            "precision": 8,
+            "confirms": null,
+            "contractAddress": null,
+            "isMarginEnabled": true,
+            "isDebitEnabled": true,
+            "chains": [
+                {
+                    "chainName": "TRC20",
+                    "chainId": "trx",
+                    "withdrawalMinSize": "10",
+                    "depositMinSize": "1",
+                    "withdrawFeeRate": "0",
+                    "withdrawalMinFee": "1",
+                    "isWithdrawEnabled": true,
+                    "isDepositEnabled": true,
+                    "confirms": 1,
+                    "preConfirms": 1,
+                    "contractAddress": "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t",
+                    "withdrawPrecision": 6,
+                    "maxWithdraw": null,
+                    "maxDeposit": null,
+                    "needTag": false
+                },
+                {
+                    "chainName": "ERC20",
+                    "chainId": "eth",
+                    "withdrawalMinSize": "10",
+                    "depositMinSize": "1",
+                    "withdrawFeeRate": "0",
+                    "withdrawalMinFee": "5",
+                    "isWithdrawEnabled": false,
+                    "isDepositEnabled": true,
+                    "confirms": 64,
+                    "preConfirms": 32,
+                    "contractAddress": "0xdac17f958d2ee523a2206206994597c13d831ec7",
+                    "withdrawPrecision": 6,
+                    "maxWithdraw": null,
+                    "maxDeposit": null,
+                    "needTag": false
+                }
+            ]
+        }"#;
+        let currency: Currency = serde_json::from_str(json).unwrap();
+
+        let enabled = currency.enabled_chains();
+        assert_eq!(enabled.len(), 1);
+        assert_eq!(enabled[0].chain_id, "trx");
+        assert_eq!(currency.chain("erc20").unwrap().chain_id, "eth");
+        assert!(currency.chain("sol").is_none());
+    }
+
+    #[test]
+    fn test_service_status() {
+        let json =
+            r#"{"code":"200000","data":{"status":"cancelonly","msg":"upgrade match engine"}}"#;
+        let res: KuCoinResponse<ServiceStatusData> = serde_json::from_str(json).unwrap();
+        let status = res.data.unwrap().status;
+
+        assert_eq!(status, ServiceStatus::CancelOnly);
+        assert!(!status.can_place_orders());
+        assert!(status.can_cancel_orders());
+    }
+}
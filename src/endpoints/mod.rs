@@ -1,4 +1,9 @@
+pub mod account;
 pub mod deposit;
+pub mod margin;
+pub mod market;
+pub mod oco;
+pub mod portfolio;
 pub mod sub_account;
 pub mod trades;
 pub mod transfer;
@@ -0,0 +1,335 @@
+use uuid::Uuid;
+
+use crate::{
+    client::rest::KuCoinClient,
+    types::{
+        KuCoinResponse, dry_run_id,
+        oco::{
+            CanceledOcoOrders, OcoListRequest, OcoOrder, OcoOrderData, OcoOrderDetail,
+            OcoOrderList, OcoOrderRequest,
+        },
+        spot::Side,
+    },
+    utils::{
+        errors::{KucoinErrors, KucoinResults},
+        num::{self, Amount, ToAmount},
+    },
+};
+
+pub struct OcoHandler<'a> {
+    pub client: &'a KuCoinClient,
+}
+
+impl OcoOrderRequest {
+    /// Create an OCO order.
+    ///
+    /// # Attributes
+    /// * symbol - Trading symbol : BTC-USDT...
+    /// * side - Buy/Sell
+    /// * price - Limit (take-profit) price.
+    /// * stop_price - Trigger price of the stop-limit leg.
+    /// * limit_price - Limit price of the stop-limit leg once triggered.
+    /// * size - Quantity in base currency, shared by both legs.
+    pub fn new(
+        symbol: &str,
+        side: Side,
+        price: impl ToAmount,
+        stop_price: impl ToAmount,
+        limit_price: impl ToAmount,
+        size: impl ToAmount,
+    ) -> Self {
+        OcoOrderRequest {
+            symbol: symbol.to_string(),
+            side,
+            price: price.to_amount(),
+            size: size.to_amount(),
+            stop_price: stop_price.to_amount(),
+            limit_price: limit_price.to_amount(),
+            trade_type: "TRADE".to_string(),
+            client_oid: Some(Uuid::new_v4().to_string()),
+            remark: None,
+        }
+    }
+
+    /// Sets the client order id (Chainable).
+    pub fn set_client_oid(mut self, client_oid: &str) -> Self {
+        self.client_oid = Some(client_oid.to_string());
+        self
+    }
+
+    /// Sets a remark/comment for the order (Chainable).
+    pub fn set_remark(mut self, remark: &str) -> Self {
+        self.remark = Some(remark.to_string());
+        self
+    }
+
+    /// Validate and build the request body.
+    ///
+    /// A sell OCO takes profit above and stops out below: `price > stop_price >= limit_price`.
+    /// A buy OCO mirrors it: `price < stop_price <= limit_price`.
+    ///
+    /// # Returns
+    /// - Request Body in json-string, or `InvalidRequest` when a value is not a positive number
+    ///   or the prices are not ordered as the side requires.
+    fn build_body(&self) -> KucoinResults<String> {
+        let price = positive("price", &self.price)?;
+        let stop_price = positive("stopPrice", &self.stop_price)?;
+        let limit_price = positive("limitPrice", &self.limit_price)?;
+        positive("size", &self.size)?;
+
+        let ordered = match self.side {
+            Side::Sell => price > stop_price && stop_price >= limit_price,
+            Side::Buy => price < stop_price && stop_price <= limit_price,
+        };
+        if !ordered {
+            let expected = match self.side {
+                Side::Sell => "price > stopPrice >= limitPrice",
+                Side::Buy => "price < stopPrice <= limitPrice",
+            };
+            return Err(KucoinErrors::InvalidRequest(format!(
+                "{:?} OCO order requires {}",
+                self.side, expected
+            )));
+        }
+        Ok(serde_json::to_string(&self)?)
+    }
+}
+
+/// Parse a strictly positive decimal field.
+fn positive(name: &str, value: &str) -> KucoinResults<Amount> {
+    match num::parse_amount(value) {
+        Some(v) if v > Amount::default() => Ok(v),
+        _ => Err(KucoinErrors::InvalidRequest(format!(
+            "OCO {} must be a positive number, got {}",
+            name, value
+        ))),
+    }
+}
+
+impl OcoListRequest {
+    /// Create an OCO order query, all filters unset.
+    pub fn new() -> Self {
+        OcoListRequest {
+            symbol: None,
+            order_ids: None,
+            start_at: None,
+            end_at: None,
+            current_page: None,
+            page_size: None,
+        }
+    }
+
+    /// Filter by symbol (Chainable).
+    pub fn set_symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_string());
+        self
+    }
+
+    /// Only these order ids (Chainable).
+    pub fn set_order_ids(mut self, order_ids: &[&str]) -> Self {
+        self.order_ids = Some(order_ids.join(","));
+        self
+    }
+
+    /// Set the start timestamp in milliseconds (Chainable).
+    pub fn set_start_at(mut self, start_at: i64) -> Self {
+        self.start_at = Some(start_at);
+        self
+    }
+
+    /// Set the end timestamp in milliseconds (Chainable).
+    pub fn set_end_at(mut self, end_at: i64) -> Self {
+        self.end_at = Some(end_at);
+        self
+    }
+
+    /// Set the page number (Chainable).
+    pub fn set_current_page(mut self, page: i64) -> Self {
+        self.current_page = Some(page);
+        self
+    }
+
+    /// Set the number of results per page (Chainable).
+    /// Note: 10 to 500, default 50.
+    pub fn set_page_size(mut self, size: i64) -> Self {
+        self.page_size = Some(size);
+        self
+    }
+}
+
+impl Default for OcoListRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> OcoHandler<'a> {
+    /// Place an OCO order, validated before sending.
+    pub async fn place(
+        &self,
+        order: OcoOrderRequest,
+    ) -> KucoinResults<KuCoinResponse<OcoOrderData>> {
+        let endpoint = "/api/v3/oco/order";
+        let body = order.build_body()?;
+
+        let res = self
+            .client
+            .send_mutation("POST", &body, endpoint, || {
+                KuCoinResponse::synthetic(OcoOrderData {
+                    order_id: dry_run_id(),
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel both legs of an OCO order by its order id.
+    pub async fn cancel_by_id(
+        &self,
+        order_id: &str,
+    ) -> KucoinResults<KuCoinResponse<CanceledOcoOrders>> {
+        let endpoint = format!("/api/v3/oco/order/{}", order_id);
+        self.cancel(&endpoint, vec![order_id.to_string()]).await
+    }
+
+    /// Cancel both legs of an OCO order by its client order id.
+    pub async fn cancel_by_client_oid(
+        &self,
+        client_oid: &str,
+    ) -> KucoinResults<KuCoinResponse<CanceledOcoOrders>> {
+        let endpoint = format!("/api/v3/oco/client-order/{}", client_oid);
+        self.cancel(&endpoint, Vec::new()).await
+    }
+
+    /// Cancel OCO orders in batch.
+    ///
+    /// # Parameters
+    /// - symbol    : Only the orders of this symbol, every symbol when `None`.
+    /// - order_ids : Only these order ids, every OCO order when empty.
+    pub async fn cancel_batch(
+        &self,
+        symbol: Option<&str>,
+        order_ids: &[&str],
+    ) -> KucoinResults<KuCoinResponse<CanceledOcoOrders>> {
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if !order_ids.is_empty() {
+            query.push(("orderIds", order_ids.join(",")));
+        }
+        if let Some(symbol) = symbol {
+            query.push(("symbol", symbol.to_string()));
+        }
+        let query = serde_urlencoded::to_string(&query)?;
+        let endpoint = if query.is_empty() {
+            "/api/v3/oco/orders".to_string()
+        } else {
+            format!("/api/v3/oco/orders?{}", query)
+        };
+        let ids = order_ids.iter().map(|id| id.to_string()).collect();
+        self.cancel(&endpoint, ids).await
+    }
+
+    /// Send a cancellation; in dry-run mode `known_ids` are reported as canceled.
+    async fn cancel(
+        &self,
+        endpoint: &str,
+        known_ids: Vec<String>,
+    ) -> KucoinResults<KuCoinResponse<CanceledOcoOrders>> {
+        let res = self
+            .client
+            .send_mutation("DELETE", "", endpoint, || {
+                KuCoinResponse::synthetic(CanceledOcoOrders {
+                    cancelled_order_ids: known_ids,
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// List OCO orders, one page at a time.
+    pub async fn list(
+        &self,
+        filter: OcoListRequest,
+    ) -> KucoinResults<KuCoinResponse<OcoOrderList>> {
+        let query = serde_urlencoded::to_string(&filter)?;
+        let endpoint = if query.is_empty() {
+            "/api/v3/oco/orders".to_string()
+        } else {
+            format!("/api/v3/oco/orders?{}", query)
+        };
+        let res = self
+            .client
+            .send::<KuCoinResponse<OcoOrderList>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get an OCO order by its order id.
+    pub async fn order_by_id(&self, order_id: &str) -> KucoinResults<KuCoinResponse<OcoOrder>> {
+        let endpoint = format!("/api/v3/oco/order/{}", order_id);
+        let res = self
+            .client
+            .send::<KuCoinResponse<OcoOrder>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get an OCO order by its client order id.
+    pub async fn order_by_client_oid(
+        &self,
+        client_oid: &str,
+    ) -> KucoinResults<KuCoinResponse<OcoOrder>> {
+        let endpoint = format!("/api/v3/oco/client-order/{}", client_oid);
+        let res = self
+            .client
+            .send::<KuCoinResponse<OcoOrder>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get an OCO order with the state of its limit and stop-limit legs.
+    pub async fn details(&self, order_id: &str) -> KucoinResults<KuCoinResponse<OcoOrderDetail>> {
+        let endpoint = format!("/api/v3/oco/order/details/{}", order_id);
+        let res = self
+            .client
+            .send::<KuCoinResponse<OcoOrderDetail>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_price_relationships() {
+        // Sell: take profit at 70k, stop at 60k, sell no lower than 59.5k.
+        let sell = OcoOrderRequest::new("BTC-USDT", Side::Sell, 70000.0, 60000.0, 59500.0, 0.01);
+        assert!(sell.build_body().is_ok());
+        let sell = OcoOrderRequest::new("BTC-USDT", Side::Sell, 60000.0, 70000.0, 59500.0, 0.01);
+        assert!(matches!(
+            sell.build_body(),
+            Err(KucoinErrors::InvalidRequest(_))
+        ));
+
+        // Buy: buy the dip at 60k, or the breakout above 70k up to 70.5k.
+        let buy = OcoOrderRequest::new("BTC-USDT", Side::Buy, 60000.0, 70000.0, 70500.0, 0.01);
+        assert!(buy.build_body().is_ok());
+        let buy = OcoOrderRequest::new("BTC-USDT", Side::Buy, 60000.0, 70000.0, 69000.0, 0.01);
+        assert!(buy.build_body().is_err());
+
+        let empty = OcoOrderRequest::new("BTC-USDT", Side::Buy, 60000.0, 70000.0, 70500.0, 0.0);
+        assert!(empty.build_body().is_err());
+    }
+
+    #[test]
+    fn test_parse_details() {
+        let json = r#"{"code":"200000","data":{"orderId":"674c3b6e688dea0007c7bab2","symbol":"BTC-USDT","clientOid":"5c52e1203aa6f37f1e493fb","orderTime":1733049198863,"status":"NEW","orders":[{"id":"vs8hoo8kqjnklv4m0038lrfq","symbol":"BTC-USDT","side":"buy","price":"94000.00000000000000000000","stopPrice":"94000.00000000000000000000","size":"0.00001000000000000000","status":"NEW"},{"id":"vs8hoo8kqjnklv4m0038lrfr","symbol":"BTC-USDT","side":"buy","price":"96000.00000000000000000000","stopPrice":"98000.00000000000000000000","size":"0.00001000000000000000","status":"NEW"}]}}"#;
+        let res: KuCoinResponse<OcoOrderDetail> = serde_json::from_str(json).unwrap();
+        let detail = res.into_data().unwrap();
+
+        assert_eq!(detail.status, crate::types::oco::OcoStatus::New);
+        assert_eq!(detail.orders.len(), 2);
+        assert_eq!(detail.orders[1].side, Side::Buy);
+    }
+}
@@ -0,0 +1,409 @@
+use std::collections::HashMap;
+
+use futures::TryStreamExt;
+
+use crate::{
+    client::rest::KuCoinClient,
+    types::{
+        portfolio::{FailedSource, Holding, HoldingSource, Portfolio, PortfolioRequest},
+        sup_account::SubAccItem,
+        transfer::AccountType,
+    },
+    utils::{errors::KucoinResults, num::Amount},
+};
+
+/// Currency used to price assets that have no direct market against the quote currency.
+const BRIDGE_CURRENCY: &str = "USDT";
+
+pub struct PortfolioHandler<'a> {
+    pub client: &'a KuCoinClient,
+}
+
+impl PortfolioRequest {
+    /// Snapshot of the spot, margin, isolated and USDT/XBT futures accounts of the master
+    /// account, valued in `quote`.
+    pub fn new(quote: &str) -> Self {
+        PortfolioRequest {
+            quote: quote.to_string(),
+            spot: true,
+            margin: true,
+            isolated: true,
+            futures: vec!["USDT".to_string(), "XBT".to_string()],
+            sub_accounts: false,
+        }
+    }
+
+    /// Include the funding and spot accounts (Chainable).
+    pub fn set_spot(mut self, enabled: bool) -> Self {
+        self.spot = enabled;
+        self
+    }
+
+    /// Include the cross margin account (Chainable).
+    pub fn set_margin(mut self, enabled: bool) -> Self {
+        self.margin = enabled;
+        self
+    }
+
+    /// Include the isolated margin accounts (Chainable).
+    pub fn set_isolated(mut self, enabled: bool) -> Self {
+        self.isolated = enabled;
+        self
+    }
+
+    /// Futures settlement currencies to include, empty to skip futures (Chainable).
+    pub fn set_futures(mut self, currencies: &[&str]) -> Self {
+        self.futures = currencies.iter().map(|c| c.to_string()).collect();
+        self
+    }
+
+    /// Include the balances of every sub-account (Chainable).
+    pub fn set_sub_accounts(mut self, enabled: bool) -> Self {
+        self.sub_accounts = enabled;
+        self
+    }
+}
+
+impl<'a> PortfolioHandler<'a> {
+    /// Merge the balances of the selected accounts by currency and value them with the last
+    /// traded prices. Assets that cannot be priced are listed in `Portfolio::unpriced`, sources
+    /// that cannot be read (missing permission, failed request) in `Portfolio::failed`.
+    pub async fn snapshot(&self, request: PortfolioRequest) -> KucoinResults<Portfolio> {
+        let mut book = HoldingBook::default();
+
+        if request.spot {
+            let res = self.add_spot(&mut book).await;
+            book.record("spot".to_string(), res);
+        }
+        if request.margin {
+            let res = self.add_margin(&mut book).await;
+            book.record("margin".to_string(), res);
+        }
+        if request.isolated {
+            let res = self.add_isolated(&mut book).await;
+            book.record("isolated".to_string(), res);
+        }
+        for currency in &request.futures {
+            let res = self.add_futures(&mut book, currency).await;
+            book.record(format!("futures:{}", currency), res);
+        }
+        if request.sub_accounts {
+            let res = self.add_sub_accounts(&mut book).await;
+            book.record("sub-accounts".to_string(), res);
+        }
+
+        let tickers = self.client.market().all_tickers().await?.into_data()?;
+        let prices: HashMap<String, Amount> = tickers
+            .ticker
+            .into_iter()
+            .filter_map(|t| {
+                let last = t.last?;
+                (last > Amount::ZERO).then_some((t.symbol, last))
+            })
+            .collect();
+
+        Ok(book.finish(&request.quote, &prices))
+    }
+
+    /// Funding and spot accounts of the master account.
+    async fn add_spot(&self, book: &mut HoldingBook) -> KucoinResults<()> {
+        for account in self.client.account().list(None, None).await?.into_data()? {
+            let source = match account.account_type {
+                AccountType::Main => HoldingSource::Main,
+                AccountType::Trade => HoldingSource::Trade,
+                // Margin balances come with their liabilities from the margin endpoints.
+                _ => continue,
+            };
+            book.add(&account.currency, source, account.balance, Amount::ZERO);
+        }
+        Ok(())
+    }
+
+    async fn add_margin(&self, book: &mut HoldingBook) -> KucoinResults<()> {
+        let margin = self
+            .client
+            .margin()
+            .cross_account(None, None)
+            .await?
+            .into_data()?;
+        for asset in margin.accounts {
+            book.add(
+                &asset.currency,
+                HoldingSource::Margin,
+                asset.total,
+                asset.liability,
+            );
+        }
+        Ok(())
+    }
+
+    async fn add_isolated(&self, book: &mut HoldingBook) -> KucoinResults<()> {
+        let isolated = self
+            .client
+            .margin()
+            .isolated_accounts(None, None, None)
+            .await?
+            .into_data()?;
+        for pair in isolated.assets {
+            for asset in [pair.base_asset, pair.quote_asset] {
+                book.add(
+                    &asset.currency,
+                    HoldingSource::Isolated,
+                    asset.total,
+                    asset.liability,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    async fn add_futures(&self, book: &mut HoldingBook, currency: &str) -> KucoinResults<()> {
+        let futures = self
+            .client
+            .account()
+            .futures_overview(Some(currency))
+            .await?
+            .into_data()?;
+        let currency = if futures.currency == "XBT" {
+            "BTC"
+        } else {
+            futures.currency.as_str()
+        };
+        book.add(
+            currency,
+            HoldingSource::Futures,
+            futures.account_equity,
+            Amount::ZERO,
+        );
+        Ok(())
+    }
+
+    /// Every sub-account, across all pages. A sub-account whose balance cannot be read is
+    /// recorded on its own; the error is only returned when the list itself fails.
+    async fn add_sub_accounts(&self, book: &mut HoldingBook) -> KucoinResults<()> {
+        let subs: Vec<SubAccItem> = self
+            .client
+            .sub_acc()
+            .fetchall_stream()
+            .try_collect()
+            .await?;
+        for sub in subs {
+            let res = self.add_sub_account(book, &sub.user_id).await;
+            book.record(format!("sub-account:{}", sub.sub_name), res);
+        }
+        Ok(())
+    }
+
+    async fn add_sub_account(&self, book: &mut HoldingBook, user_id: &str) -> KucoinResults<()> {
+        let balance = self.client.sub_acc().balance(user_id).await?.into_data()?;
+        let source = HoldingSource::SubAccount(balance.sub_name.clone());
+        let accounts = balance
+            .main_accounts
+            .iter()
+            .map(|a| (&a.currency, &a.balance))
+            .chain(
+                balance
+                    .trade_accounts
+                    .iter()
+                    .map(|a| (&a.currency, &a.balance)),
+            )
+            .chain(
+                balance
+                    .margin_accounts
+                    .iter()
+                    .map(|a| (&a.currency, &a.balance)),
+            );
+        for (currency, amount) in accounts {
+            let (Some(currency), Some(amount)) = (currency, amount) else {
+                continue;
+            };
+            book.add(currency, source.clone(), *amount, Amount::ZERO);
+        }
+        Ok(())
+    }
+}
+
+/// Price of one `currency` in `quote` from last prices keyed by symbol ("BASE-QUOTE").
+///
+/// Tries the direct pair, the inverse pair, then a route through `BRIDGE_CURRENCY`.
+pub fn price_in(currency: &str, quote: &str, prices: &HashMap<String, Amount>) -> Option<Amount> {
+    let direct = |base: &str, quote: &str| -> Option<Amount> {
+        if base == quote {
+            return Some(Amount::ONE);
+        }
+        prices
+            .get(&format!("{}-{}", base, quote))
+            .copied()
+            .or_else(|| {
+                let inverse = prices.get(&format!("{}-{}", quote, base))?;
+                Amount::ONE.checked_div(*inverse)
+            })
+    };
+
+    direct(currency, quote)
+        .or_else(|| direct(currency, BRIDGE_CURRENCY)?.checked_mul(direct(BRIDGE_CURRENCY, quote)?))
+}
+
+/// Accumulates balances by currency, and the sources that failed.
+#[derive(Default)]
+struct HoldingBook {
+    holdings: HashMap<String, Holding>,
+    failed: Vec<FailedSource>,
+}
+
+impl HoldingBook {
+    /// Keep track of `source` if reading it failed.
+    fn record(&mut self, source: String, res: KucoinResults<()>) {
+        if let Err(e) = res {
+            self.failed.push(FailedSource {
+                source,
+                error: e.to_string(),
+            });
+        }
+    }
+
+    fn add(&mut self, currency: &str, source: HoldingSource, amount: Amount, liability: Amount) {
+        if amount.is_zero() && liability.is_zero() {
+            return;
+        }
+        let holding = self
+            .holdings
+            .entry(currency.to_string())
+            .or_insert_with(|| Holding {
+                currency: currency.to_string(),
+                total: Amount::ZERO,
+                liability: Amount::ZERO,
+                sources: HashMap::new(),
+                price: None,
+                value: None,
+            });
+        holding.total += amount;
+        holding.liability += liability;
+        *holding.sources.entry(source).or_default() += amount;
+    }
+
+    fn finish(self, quote: &str, prices: &HashMap<String, Amount>) -> Portfolio {
+        let mut holdings: Vec<Holding> = self.holdings.into_values().collect();
+        holdings.sort_by(|a, b| a.currency.cmp(&b.currency));
+
+        let mut total_value = Amount::ZERO;
+        let mut unpriced = Vec::new();
+        for holding in &mut holdings {
+            holding.price = price_in(&holding.currency, quote, prices);
+            holding.value = holding
+                .price
+                .and_then(|price| holding.net().checked_mul(price));
+            match holding.value {
+                Some(value) => total_value += value,
+                None => unpriced.push(holding.currency.clone()),
+            }
+        }
+
+        Portfolio {
+            quote: quote.to_string(),
+            holdings,
+            total_value,
+            unpriced,
+            failed: self.failed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::{fixtures::amt, mock::MockServer, rest::Credentials};
+
+    #[test]
+    fn test_price_routes() {
+        let prices = HashMap::from([
+            ("BTC-USDT".to_string(), amt("50000")),
+            ("ETH-BTC".to_string(), amt("0.05")),
+            ("USDT-EUR".to_string(), amt("0.9")),
+        ]);
+
+        assert_eq!(price_in("USDT", "USDT", &prices), Some(amt("1")));
+        assert_eq!(price_in("BTC", "USDT", &prices), Some(amt("50000")));
+        assert_eq!(price_in("USDT", "BTC", &prices), Some(amt("0.00002")));
+        assert_eq!(price_in("BTC", "EUR", &prices), Some(amt("45000")));
+        assert_eq!(price_in("ETH", "EUR", &prices), None);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_sub_accounts() {
+        let sub = |name: &str, user_id: &str| {
+            format!(
+                r#"{{"userId":"{}","uid":1,"subName":"{}","status":2,"type":0,"access":"All","createdAt":1668562696000,"remarks":"","tradeTypes":["Spot"],"openedTradeTypes":["Spot"],"hostedStatus":null}}"#,
+                user_id, name
+            )
+        };
+        let first = format!(
+            r#"{{"code":"200000","data":{{"currentPage":1,"pageSize":100,"totalNum":2,"totalPage":2,"items":[{}]}}}}"#,
+            sub("bot1", "u1")
+        );
+        let second = format!(
+            r#"{{"code":"200000","data":{{"currentPage":2,"pageSize":100,"totalNum":2,"totalPage":2,"items":[{}]}}}}"#,
+            sub("bot2", "u2")
+        );
+        let server = MockServer::start(move |target| {
+            if target.starts_with("/api/v2/sub/user?currentPage=1") {
+                first.clone()
+            } else if target.starts_with("/api/v2/sub/user?currentPage=2") {
+                second.clone()
+            } else if target == "/api/v1/sub-accounts/u1" {
+                r#"{"code":"200000","data":{"subUserId":"u1","subName":"bot1","mainAccounts":[{"currency":"BTC","balance":"0.5","available":"0.5","holds":"0"}],"tradeAccounts":[],"marginAccounts":[],"tradeHFAccounts":[]}}"#.to_string()
+            } else if target == "/api/v1/market/allTickers" {
+                r#"{"code":"200000","data":{"time":1,"ticker":[{"symbol":"BTC-USDT","last":"50000"}]}}"#.to_string()
+            } else {
+                r#"{"code":"400001","msg":"Unauthorized"}"#.to_string()
+            }
+        });
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client.base_link = server.url.clone();
+        client.futures_link = server.url.clone();
+
+        let request = PortfolioRequest::new("USDT")
+            .set_spot(false)
+            .set_margin(false)
+            .set_isolated(false)
+            .set_futures(&["USDT"])
+            .set_sub_accounts(true);
+        let portfolio = client.portfolio().snapshot(request).await.unwrap();
+
+        assert_eq!(portfolio.total_value, amt("25000"));
+        let failed: Vec<&str> = portfolio.failed.iter().map(|f| f.source.as_str()).collect();
+        assert_eq!(failed, vec!["futures:USDT", "sub-account:bot2"]);
+        assert!(
+            server
+                .requests()
+                .iter()
+                .any(|target| target.starts_with("/api/v2/sub/user?currentPage=2"))
+        );
+    }
+
+    #[test]
+    fn test_merge_and_value() {
+        let prices = HashMap::from([("BTC-USDT".to_string(), amt("50000"))]);
+        let mut book = HoldingBook::default();
+        book.add("BTC", HoldingSource::Main, amt("0.5"), Amount::ZERO);
+        book.add("BTC", HoldingSource::Margin, amt("1"), amt("0.5"));
+        book.add("USDT", HoldingSource::Trade, amt("100"), Amount::ZERO);
+        book.add(
+            "XYZ",
+            HoldingSource::SubAccount("bot".to_string()),
+            amt("42"),
+            Amount::ZERO,
+        );
+        book.add("ETH", HoldingSource::Trade, Amount::ZERO, Amount::ZERO);
+
+        let portfolio = book.finish("USDT", &prices);
+
+        assert_eq!(portfolio.holdings.len(), 3);
+        let btc = &portfolio.holdings[0];
+        assert_eq!(btc.total, amt("1.5"));
+        assert_eq!(btc.sources[&HoldingSource::Margin], amt("1"));
+        assert_eq!(btc.value, Some(amt("50000")));
+        assert_eq!(portfolio.total_value, amt("50100"));
+        assert_eq!(portfolio.unpriced, vec!["XYZ".to_string()]);
+    }
+}
@@ -1,11 +1,24 @@
+use futures::{Stream, TryStreamExt, stream};
+
 use crate::{
-    client::rest::KuCoinClient,
+    client::rest::{KuCoinClient, REDACTED},
     types::{
         KuCoinResponse,
-        sup_account::{Expire, SubAccBalance, SubAccData, SubAccListData, SubAccRequest},
+        account::Permission,
+        dry_run_id,
+        sup_account::{
+            Expire, SubAccBalance, SubAccData, SubAccItem, SubAccListData, SubAccRequest,
+        },
+    },
+    utils::{
+        errors::{KucoinErrors, KucoinResults},
+        time,
     },
 };
 
+/// Maximum sub-accounts per page of `/api/v2/sub/user`.
+const SUB_ACC_PAGE_SIZE: i64 = 100;
+
 impl SubAccRequest {
     /// Request to add a new SubAccRequest API
     pub fn new(name: &str, remark: &str, passphrase: &str) -> Self {
@@ -40,9 +53,9 @@ impl SubAccRequest {
     /// InnerTransfer (Flex Transfer) permissions can be set.
     ///
     /// # Examples
-    /// * "General,Spot,Futures,Unified")
-    pub fn set_permission(mut self, permission: &str) -> Self {
-        self.permission = Some(permission.to_string());
+    /// * `&[Permission::General, Permission::Spot]`
+    pub fn set_permission(mut self, permissions: &[Permission]) -> Self {
+        self.permission = Some(permissions.to_vec());
         self
     }
 }
@@ -75,11 +88,28 @@ impl<'a> SubAccHander<'a> {
         let payload = serde_json::to_string(&request).unwrap();
 
         self.client
-            .send::<KuCoinResponse<SubAccData>>("POST", &payload, enpoint)
+            .send_mutation("POST", &payload, enpoint, || {
+                KuCoinResponse::synthetic(SubAccData {
+                    api_key: dry_run_id(),
+                    api_secret: String::new(),
+                    api_version: 3,
+                    created_at: time::get_timestamp().parse().unwrap_or_default(),
+                    ip_whitelist: request.ip_whitelist.clone(),
+                    passphrase: REDACTED.to_string(),
+                    permission: request
+                        .permission
+                        .as_deref()
+                        .map(Permission::join_list)
+                        .unwrap_or_else(|| "General".to_string()),
+                    remark: request.remark.clone(),
+                    sub_name: request.sub_name.clone(),
+                })
+            })
             .await
     }
 
-    /// Get every sub-account summary info.
+    /// Get the first page of sub-account summaries (10 by default).
+    /// Use `fetchall_stream` to walk every page.
     pub async fn fetchall(&self) -> Result<KuCoinResponse<SubAccListData>, reqwest::Error> {
         let endpoint = "/api/v2/sub/user";
         self.client
@@ -87,6 +117,47 @@ impl<'a> SubAccHander<'a> {
             .await
     }
 
+    /// Get one page of sub-account summaries.
+    ///
+    /// # Arguments
+    /// * `current_page` - Page number, starting at 1.
+    /// * `page_size` - Sub-accounts per page, 1 to 100.
+    pub async fn fetch_page(
+        &self,
+        current_page: i64,
+        page_size: i64,
+    ) -> KucoinResults<KuCoinResponse<SubAccListData>> {
+        let endpoint = format!(
+            "/api/v2/sub/user?currentPage={}&pageSize={}",
+            current_page, page_size
+        );
+        let res = self
+            .client
+            .send::<KuCoinResponse<SubAccListData>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Walk every page of sub-account summaries, up to `totalPage`.
+    pub fn fetchall_stream(&self) -> impl Stream<Item = KucoinResults<SubAccItem>> + 'a {
+        let client = self.client;
+
+        stream::try_unfold(Some(1), move |next| async move {
+            let Some(current_page) = next else {
+                return Ok::<_, KucoinErrors>(None);
+            };
+            let page = client
+                .sub_acc()
+                .fetch_page(current_page, SUB_ACC_PAGE_SIZE)
+                .await?
+                .into_data()?;
+            let following = (page.current_page < page.total_page).then_some(page.current_page + 1);
+            Ok(Some((page.items, following)))
+        })
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
     pub async fn balance(
         &self,
         user_id: &str,
@@ -101,6 +172,7 @@ impl<'a> SubAccHander<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::rest::Credentials;
 
     #[test]
     fn test_create_basic_request() {
@@ -121,13 +193,35 @@ mod tests {
         assert_eq!(req.ip_whitelist, Some("192.168.1.1,10.0.0.1".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_dry_run_hides_passphrase() {
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client.set_dry_run(true);
+
+        let request = SubAccRequest::new("bot", "remark", "hunter22");
+        let data = client
+            .sub_acc()
+            .add_api(request)
+            .await
+            .unwrap()
+            .into_data()
+            .unwrap();
+        assert_eq!(data.passphrase, REDACTED);
+        assert_eq!(data.sub_name, "bot");
+    }
+
     #[test]
     fn test_full_builder_chain() {
         let req = SubAccRequest::new("user", "remark", "pass")
-            .set_permission("General,Spot")
+            .set_permission(&[Permission::General, Permission::Spot])
             .add_ipwhitelist("1.1.1.1");
 
-        assert_eq!(req.permission, Some("General,Spot".to_string()));
+        assert_eq!(
+            req.permission,
+            Some(vec![Permission::General, Permission::Spot])
+        );
+        let body = serde_json::to_string(&req).unwrap();
+        assert!(body.contains(r#""permission":"General,Spot""#));
         assert_eq!(req.ip_whitelist, Some("1.1.1.1".to_string()));
     }
 }
@@ -1,21 +1,105 @@
+use std::collections::{HashMap, HashSet};
+
+use futures::{Stream, StreamExt, TryStreamExt, stream};
 use uuid::Uuid;
 
 use crate::{
     client::rest::KuCoinClient,
     types::{
-        KuCoinResponse,
+        KuCoinResponse, SUCCESS_CODE, dry_run_id,
+        market::SymbolInfo,
         spot::{
-            BatchOrderResult, BatchSpotContract, CancelAllRes, Side, SpotCancelRequest,
-            SpotCanceledData, SpotData, SpotDatum, SpotOrderRequest, Stp, TimeInForce, TradeType,
+            ActiveSymbols, BatchOrderOutcome, BatchOrderReport, BatchOrderResult,
+            BatchSpotContract, CancelAllRes, CanceledClientOrder, CanceledOrder,
+            CanceledStopClientOrder, CanceledStopOrders, DcpData, DcpRequest, DcpSettings,
+            DoneOrderList, DoneOrdersRequest, FailedSymbol, Fill, FillList, FillsRequest,
+            OpenOrders, OrderStatus, OrderViolation, Side, SpotCancelRequest, SpotCanceledData,
+            SpotData, SpotDatum, SpotModifiedData, SpotModifyRequest, SpotOrderRequest,
+            SpotOrderResult, StopOrder, StopOrderData, StopOrderList, StopOrderListRequest,
+            StopOrderRequest, StopType, Stp, SyncCancelData, SyncOrderData, SyncOrderResult,
+            TimeInForce, TradeType, Type,
         },
     },
-    utils::errors::KucoinResults,
+    utils::{
+        errors::{KucoinErrors, KucoinResults},
+        num::{self, Amount, ToAmount},
+        time,
+    },
 };
 
+/// Default page size of `/api/v1/hf/orders/done`.
+const DONE_ORDERS_LIMIT: i64 = 20;
+/// Server maximum of orders per `/api/v1/hf/orders/done` request.
+const DONE_ORDERS_MAX_LIMIT: i64 = 100;
+/// Default page size of `/api/v1/hf/fills`.
+const FILLS_LIMIT: i64 = 20;
+/// Server maximum of fills per `/api/v1/hf/fills` request.
+const FILLS_MAX_LIMIT: i64 = 100;
+/// Maximum orders in one HF batch request.
+const MULTI_ORDERS_LIMIT: usize = 20;
+/// Batch requests in flight at once when placing many orders.
+const MULTI_ORDERS_CONCURRENCY: usize = 4;
+/// Per-symbol requests in flight at once when fanning out over the active symbols.
+const SYMBOL_CONCURRENCY: usize = 4;
+/// Widest time range `/api/v1/hf/orders/done` accepts in one query (7 days, in milliseconds).
+const DONE_ORDERS_MAX_WINDOW: i64 = 7 * 24 * 60 * 60 * 1000;
+
 pub struct SpotHandler<'a> {
     pub client: &'a KuCoinClient,
 }
 
+impl DoneOrdersRequest {
+    /// Create a closed-order query for `symbol`, other filters unset (last 7 days).
+    pub fn new(symbol: &str) -> Self {
+        DoneOrdersRequest {
+            symbol: symbol.to_string(),
+            side: None,
+            order_type: None,
+            last_id: None,
+            limit: None,
+            start_at: None,
+            end_at: None,
+        }
+    }
+
+    /// Filter by side (Chainable).
+    pub fn set_side(mut self, side: Side) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    /// Filter by order type (Chainable).
+    pub fn set_type(mut self, order_type: Type) -> Self {
+        self.order_type = Some(order_type);
+        self
+    }
+
+    /// Continue after the page that returned this `lastId` (Chainable).
+    pub fn set_last_id(mut self, last_id: i64) -> Self {
+        self.last_id = Some(last_id);
+        self
+    }
+
+    /// Set the number of orders per request (Chainable).
+    /// Note: API default 20, max 100; larger values are clamped to 100.
+    pub fn set_limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit.clamp(1, DONE_ORDERS_MAX_LIMIT));
+        self
+    }
+
+    /// Set the start timestamp in milliseconds (Chainable).
+    pub fn set_start_at(mut self, start_at: i64) -> Self {
+        self.start_at = Some(start_at);
+        self
+    }
+
+    /// Set the end timestamp in milliseconds (Chainable).
+    pub fn set_end_at(mut self, end_at: i64) -> Self {
+        self.end_at = Some(end_at);
+        self
+    }
+}
+
 impl SpotOrderRequest {
     /// Create a new payload for spottrade.
     ///
@@ -52,22 +136,22 @@ impl SpotOrderRequest {
 
     /// Sets the quantity for the order.
     /// Usually required for Limit orders.
-    pub fn set_size(mut self, size: f64) -> Self {
-        self.size = Some(size.to_string());
+    pub fn set_size(mut self, size: impl ToAmount) -> Self {
+        self.size = Some(size.to_amount());
         self
     }
 
     /// Sets the price for the order.
     /// Required for Limit orders.
-    pub fn set_price(mut self, price: f64) -> Self {
-        self.price = Some(price.to_string());
+    pub fn set_price(mut self, price: impl ToAmount) -> Self {
+        self.price = Some(price.to_amount());
         self
     }
 
     /// Sets the funds (quote currency amount) for the order.
     /// Often used for Market Buy orders (e.g., "Buy 100 USDT worth of BTC").
-    pub fn set_funds(mut self, funds: f64) -> Self {
-        self.funds = Some(funds.to_string());
+    pub fn set_funds(mut self, funds: impl ToAmount) -> Self {
+        self.funds = Some(funds.to_amount());
         self
     }
 
@@ -99,8 +183,8 @@ impl SpotOrderRequest {
     }
 
     /// Sets the visible size for Iceberg orders.
-    pub fn set_visible_size(mut self, visible_size: f64) -> Self {
-        self.visible_size = Some(visible_size.to_string());
+    pub fn set_visible_size(mut self, visible_size: impl ToAmount) -> Self {
+        self.visible_size = Some(visible_size.to_amount());
         self
     }
 
@@ -121,6 +205,151 @@ impl SpotOrderRequest {
         self.cancel_after = Some(cancel_after);
         self
     }
+
+    /// Check the order against the trading rules of its symbol.
+    ///
+    /// Verifies that the order type has the fields it needs, that price, size, funds and
+    /// visible size are multiples of their increments, and that size and funds are within the
+    /// symbol min/max (including `minFunds` for limit orders).
+    ///
+    /// # Returns
+    /// - `Ok(())`, or `KucoinErrors::OrderValidation` listing every violation found.
+    pub fn validate(&self, info: &SymbolInfo) -> KucoinResults<()> {
+        let mut violations = Vec::new();
+
+        if self.symbol != info.symbol {
+            violations.push(OrderViolation::SymbolMismatch {
+                expected: info.symbol.clone(),
+                got: self.symbol.clone(),
+            });
+        }
+        if !info.enable_trading {
+            violations.push(OrderViolation::TradingDisabled);
+        }
+
+        match self.spot_contract_type {
+            TradeType::Limit => {
+                if self.price.is_none() {
+                    violations.push(OrderViolation::MissingPrice);
+                }
+                if self.size.is_none() {
+                    violations.push(OrderViolation::MissingSize);
+                }
+            }
+            TradeType::Market => {
+                if self.size.is_some() == self.funds.is_some() {
+                    violations.push(OrderViolation::SizeOrFunds);
+                }
+            }
+        }
+        if self.iceberg == Some(true) && self.visible_size.is_none() {
+            violations.push(OrderViolation::MissingVisibleSize);
+        }
+
+        let price = number("price", self.price.as_deref(), &mut violations);
+        let size = number("size", self.size.as_deref(), &mut violations);
+        let funds = number("funds", self.funds.as_deref(), &mut violations);
+        let visible_size = number("visibleSize", self.visible_size.as_deref(), &mut violations);
+
+        if let Some(price) = price {
+            check_increment("price", price, info.price_increment, &mut violations);
+        }
+        if let Some(size) = size {
+            check_increment("size", size, info.base_increment, &mut violations);
+            check_range(
+                "size",
+                size,
+                info.base_min_size,
+                info.base_max_size,
+                &mut violations,
+            );
+        }
+        if let Some(funds) = funds {
+            check_increment("funds", funds, info.quote_increment, &mut violations);
+            check_range(
+                "funds",
+                funds,
+                info.quote_min_size,
+                info.quote_max_size,
+                &mut violations,
+            );
+        }
+        if let Some(visible_size) = visible_size {
+            check_increment(
+                "visibleSize",
+                visible_size,
+                info.base_increment,
+                &mut violations,
+            );
+        }
+        if let (TradeType::Limit, Some(price), Some(size), Some(min_funds)) =
+            (&self.spot_contract_type, price, size, info.min_funds)
+            && price * size < min_funds
+        {
+            violations.push(OrderViolation::BelowMin {
+                field: "funds",
+                value: price * size,
+                min: min_funds,
+            });
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(KucoinErrors::OrderValidation(violations))
+        }
+    }
+}
+
+/// Parse an optional order field, recording a violation when it is not a positive number.
+fn number(
+    field: &'static str,
+    value: Option<&str>,
+    violations: &mut Vec<OrderViolation>,
+) -> Option<Amount> {
+    let raw = value?;
+    match num::parse_amount(raw) {
+        Some(v) if v > Amount::default() => Some(v),
+        _ => {
+            violations.push(OrderViolation::InvalidNumber {
+                field,
+                value: raw.to_string(),
+            });
+            None
+        }
+    }
+}
+
+/// Record a violation when `value` is not a multiple of `increment`.
+fn check_increment(
+    field: &'static str,
+    value: Amount,
+    increment: Amount,
+    violations: &mut Vec<OrderViolation>,
+) {
+    if !num::is_multiple(value, increment) {
+        violations.push(OrderViolation::NotIncrement {
+            field,
+            value,
+            increment,
+        });
+    }
+}
+
+/// Record a violation when `value` is outside `[min, max]`.
+fn check_range(
+    field: &'static str,
+    value: Amount,
+    min: Amount,
+    max: Amount,
+    violations: &mut Vec<OrderViolation>,
+) {
+    if value < min {
+        violations.push(OrderViolation::BelowMin { field, value, min });
+    }
+    if value > max {
+        violations.push(OrderViolation::AboveMax { field, value, max });
+    }
 }
 
 impl BatchSpotContract {
@@ -138,150 +367,2036 @@ impl BatchSpotContract {
 
 impl SpotCancelRequest {
     /// Generate cancel partial order contact.
-    pub fn new(order_id: &str, cancel_size: f64, symbol: &str) -> Self {
+    pub fn new(order_id: &str, cancel_size: impl ToAmount, symbol: &str) -> Self {
         SpotCancelRequest {
             order_id: order_id.to_string(),
-            cancel_size: cancel_size.to_string(),
+            cancel_size: cancel_size.to_amount(),
             symbol: symbol.to_string(),
         }
     }
 }
 
-impl<'a> SpotHandler<'a> {
-    /// Place a single order
-    pub async fn place_order(
-        &self,
-        order: SpotOrderRequest,
-    ) -> KucoinResults<KuCoinResponse<SpotData>> {
-        let endpoint = "/api/v1/hf/orders";
-        let body = serde_json::to_string(&order)?;
+impl SpotModifyRequest {
+    /// Modify the order identified by its order id.
+    pub fn by_order_id(symbol: &str, order_id: &str) -> Self {
+        SpotModifyRequest {
+            symbol: symbol.to_string(),
+            client_oid: None,
+            order_id: Some(order_id.to_string()),
+            new_price: None,
+            new_size: None,
+        }
+    }
 
-        let res = self
-            .client
-            .send::<KuCoinResponse<SpotData>>("POST", &body, endpoint)
-            .await?;
-        Ok(res)
+    /// Modify the order identified by its client order id.
+    pub fn by_client_oid(symbol: &str, client_oid: &str) -> Self {
+        SpotModifyRequest {
+            symbol: symbol.to_string(),
+            client_oid: Some(client_oid.to_string()),
+            order_id: None,
+            new_price: None,
+            new_size: None,
+        }
     }
 
-    /// Place batch orders
-    pub async fn place_multi_orders(
-        &self,
-        orders: BatchSpotContract,
-    ) -> KucoinResults<BatchOrderResult> {
-        // Assuming BatchOrderResult was renamed
-        let endpoint = "/api/v1/hf/orders/multi";
-        let body = serde_json::to_string(&orders)?;
+    /// Sets the new price.
+    pub fn set_new_price(mut self, price: impl ToAmount) -> Self {
+        self.new_price = Some(price.to_amount());
+        self
+    }
 
-        let res = self
-            .client
-            .send::<BatchOrderResult>("POST", &body, endpoint)
-            .await?;
-        Ok(res)
+    /// Sets the new size.
+    pub fn set_new_size(mut self, size: impl ToAmount) -> Self {
+        self.new_size = Some(size.to_amount());
+        self
     }
 
-    /// Cancel partial order
-    pub async fn cancel_order(
-        &self,
-        req: SpotCancelRequest,
-    ) -> KucoinResults<KuCoinResponse<SpotCanceledData>> {
-        // Construct endpoint with query params
-        let endpoint = format!(
-            "/api/v1/hf/orders/cancel/{}?symbol={}&cancelSize={}",
-            req.order_id, req.symbol, req.cancel_size
-        );
+    /// Validate the request and serialize it.
+    ///
+    /// # Returns
+    /// - Request Body in json-string, or `InvalidRequest` when neither a new price nor a new
+    ///   size is set, or when the order is not identified by exactly one id.
+    fn build_body(&self) -> KucoinResults<String> {
+        if self.new_price.is_none() && self.new_size.is_none() {
+            return Err(KucoinErrors::InvalidRequest(
+                "modify order requires a new price and/or a new size".to_string(),
+            ));
+        }
+        if self.order_id.is_some() == self.client_oid.is_some() {
+            return Err(KucoinErrors::InvalidRequest(
+                "modify order requires either orderId or clientOid".to_string(),
+            ));
+        }
+        Ok(serde_json::to_string(&self)?)
+    }
+}
 
-        let res = self
-            .client
-            .send::<KuCoinResponse<SpotCanceledData>>("DELETE", "", &endpoint)
-            .await?;
-        Ok(res)
+impl StopOrderRequest {
+    /// Create a stop order that places a `trade_type` order once the last price crosses
+    /// `stop_price` in the `stop` direction.
+    ///
+    /// # Attributes
+    /// * trade_type - Market/Limit, if limit the 'price' and 'size' must be set.
+    /// * symbol - Trading symbol : BTC-USDT...
+    /// * side - Buy/Sell
+    /// * stop - Loss (price falls to the trigger) / Entry (price rises to the trigger)
+    /// * stop_price - Trigger price
+    pub fn new(
+        trade_type: TradeType,
+        symbol: &str,
+        side: Side,
+        stop: StopType,
+        stop_price: impl ToAmount,
+    ) -> Self {
+        StopOrderRequest {
+            client_oid: Some(Uuid::new_v4().to_string()),
+            side,
+            symbol: symbol.to_string(),
+            order_type: trade_type,
+            stop,
+            stop_price: stop_price.to_amount(),
+            price: None,
+            size: None,
+            funds: None,
+            time_in_force: None,
+            cancel_after: None,
+            post_only: None,
+            hidden: None,
+            iceberg: None,
+            visible_size: None,
+            stp: None,
+            remark: None,
+        }
     }
 
-    /// Get open orders
-    pub async fn list_orders_open(
-        &self,
-        ticker: &str,
-    ) -> KucoinResults<KuCoinResponse<Vec<SpotDatum>>> {
-        let endpoint = format!("/api/v1/hf/orders/active?symbol={}", ticker);
-        let res = self
-            .client
-            .send::<KuCoinResponse<Vec<SpotDatum>>>("GET", "", &endpoint)
-            .await?;
-        Ok(res)
+    /// Sets the quantity for the order.
+    pub fn set_size(mut self, size: impl ToAmount) -> Self {
+        self.size = Some(size.to_amount());
+        self
     }
 
-    pub async fn close_all(&self) -> KucoinResults<KuCoinResponse<CancelAllRes>> {
-        let endpoint = format!("/api/v1/hf/orders/cancelAll");
-        let res = self
-            .client
-            .send::<KuCoinResponse<CancelAllRes>>("DELETE", "", &endpoint)
-            .await?;
-        Ok(res)
+    /// Sets the limit price used once triggered.
+    pub fn set_price(mut self, price: impl ToAmount) -> Self {
+        self.price = Some(price.to_amount());
+        self
     }
-}
 
-#[cfg(test)]
-mod test {
-    use crate::client::rest::Credentials;
+    /// Sets the funds (quote currency amount), market orders only.
+    pub fn set_funds(mut self, funds: impl ToAmount) -> Self {
+        self.funds = Some(funds.to_amount());
+        self
+    }
 
-    use super::*;
-    use std::env;
+    /// Sets the Time In Force (e.g., "GTC", "IOC", "FOK").
+    pub fn set_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
 
-    #[tokio::test]
-    async fn test_send_order() {
-        // 1. Setup Credentials
-        let credentials = Credentials::new(
-            &env::var("api_key").unwrap(),
-            &env::var("api_secret").unwrap(),
-            &env::var("api_passphrase").unwrap(),
-        );
+    /// Sets the cancel_after timeout in seconds (GTT).
+    pub fn set_cancel_after(mut self, cancel_after: i64) -> Self {
+        self.cancel_after = Some(cancel_after);
+        self
+    }
 
-        // 2. Initialize Client
-        let client = KuCoinClient::new(credentials);
+    /// Sets the Post Only flag.
+    pub fn set_post_only(mut self, post_only: bool) -> Self {
+        self.post_only = Some(post_only);
+        self
+    }
 
-        // 3. Generate SpotContract.
-        let open_long_btc = SpotOrderRequest::new(TradeType::Market, "BTC-USDT", Side::Buy)
-            .set_funds(0.0)
-            .set_remark("syndicate");
+    /// Sets the hidden flag.
+    pub fn set_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = Some(hidden);
+        self
+    }
 
-        // 4. Execute.
-        match client.spot().place_order(open_long_btc).await {
-            Ok(res) => println!("Trade Order: {:#?}", res),
-            Err(e) => println!("Err: {:?}", e),
-        }
+    /// Sets the Iceberg flag.
+    pub fn set_iceberg(mut self, iceberg: bool) -> Self {
+        self.iceberg = Some(iceberg);
+        self
     }
 
-    #[tokio::test]
-    async fn test_send_multi_orders() {
-        // 1. Setup Credentials
-        let credentials = Credentials::new(
-            &env::var("api_key").unwrap(),
-            &env::var("api_secret").unwrap(),
-            &env::var("api_passphrase").unwrap(),
-        );
+    /// Sets the visible size for Iceberg orders.
+    pub fn set_visible_size(mut self, visible_size: impl ToAmount) -> Self {
+        self.visible_size = Some(visible_size.to_amount());
+        self
+    }
 
-        // 2. Initialize Client
-        let client = KuCoinClient::new(credentials);
+    /// Sets Self-Trade Prevention (STP) mode.
+    pub fn set_stp(mut self, stp: Stp) -> Self {
+        self.stp = Some(stp);
+        self
+    }
 
-        // 3. Generate SpotContracts.
-        let btc_contract = SpotOrderRequest::new(TradeType::Market, "BTC-USDT", Side::Buy)
-            .set_funds(0.0)
-            .set_remark("syndicate");
-        let sol_contract = SpotOrderRequest::new(TradeType::Market, "SOL-USDT", Side::Buy)
-            .set_funds(0.0)
-            .set_remark("syndicate2");
+    /// Sets a remark/comment for the order.
+    pub fn set_remark(mut self, remark: &str) -> Self {
+        self.remark = Some(remark.to_string());
+        self
+    }
 
-        let orders = BatchSpotContract::new()
-            .add_order(btc_contract)
-            .add_order(sol_contract);
+    /// Validate and build the request body.
+    ///
+    /// # Returns
+    /// - Request Body in json-string, or `InvalidRequest` when a limit order lacks a price or
+    ///   size, or a market order has neither (or both) size and funds.
+    fn build_body(&self) -> KucoinResults<String> {
+        match self.order_type {
+            TradeType::Limit if self.price.is_none() || self.size.is_none() => {
+                return Err(KucoinErrors::InvalidRequest(
+                    "limit stop order requires a price and a size".to_string(),
+                ));
+            }
+            TradeType::Market if self.size.is_some() == self.funds.is_some() => {
+                return Err(KucoinErrors::InvalidRequest(
+                    "market stop order requires either a size or funds".to_string(),
+                ));
+            }
+            _ => {}
+        }
+        Ok(serde_json::to_string(&self)?)
+    }
+}
 
-        // 4. Execute
-        match client.spot().place_multi_orders(orders).await {
-            Ok(res) => println!("Trade Orders: {:#?}", res),
-            Err(e) => println!("Multi Orders Err: {:?}", e),
+impl StopOrderListRequest {
+    /// Create a stop order query, all filters unset.
+    pub fn new() -> Self {
+        StopOrderListRequest {
+            symbol: None,
+            side: None,
+            order_type: None,
+            stop: None,
+            order_ids: None,
+            start_at: None,
+            end_at: None,
+            current_page: None,
+            page_size: None,
         }
     }
 
+    /// Filter by symbol (Chainable).
+    pub fn set_symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_string());
+        self
+    }
+
+    /// Filter by side (Chainable).
+    pub fn set_side(mut self, side: Side) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    /// Filter by order type (Chainable).
+    pub fn set_type(mut self, order_type: TradeType) -> Self {
+        self.order_type = Some(order_type);
+        self
+    }
+
+    /// Filter by trigger direction (Chainable).
+    pub fn set_stop(mut self, stop: StopType) -> Self {
+        self.stop = Some(stop);
+        self
+    }
+
+    /// Only these order ids (Chainable).
+    pub fn set_order_ids(mut self, order_ids: &[&str]) -> Self {
+        self.order_ids = Some(order_ids.join(","));
+        self
+    }
+
+    /// Set the start timestamp in milliseconds (Chainable).
+    pub fn set_start_at(mut self, start_at: i64) -> Self {
+        self.start_at = Some(start_at);
+        self
+    }
+
+    /// Set the end timestamp in milliseconds (Chainable).
+    pub fn set_end_at(mut self, end_at: i64) -> Self {
+        self.end_at = Some(end_at);
+        self
+    }
+
+    /// Set the page number (Chainable).
+    pub fn set_current_page(mut self, page: i64) -> Self {
+        self.current_page = Some(page);
+        self
+    }
+
+    /// Set the number of results per page (Chainable).
+    pub fn set_page_size(mut self, size: i64) -> Self {
+        self.page_size = Some(size);
+        self
+    }
+}
+
+impl Default for StopOrderListRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DcpRequest {
+    /// Cancel every open HF order after `timeout` seconds (5 to 86400) without a refresh.
+    pub fn new(timeout: i64) -> Self {
+        DcpRequest {
+            timeout,
+            symbols: None,
+        }
+    }
+
+    /// Turn Dead Cancel Protection off.
+    pub fn disable() -> Self {
+        Self::new(-1)
+    }
+
+    /// Only protect the orders of these symbols, at most 50 (Chainable).
+    pub fn set_symbols(mut self, symbols: &[&str]) -> Self {
+        self.symbols = Some(symbols.join(","));
+        self
+    }
+
+    /// Validate and build the request body.
+    ///
+    /// # Returns
+    /// - Request Body in json-string, or `InvalidRequest` when the timeout is neither -1 nor
+    ///   within 5..=86400 seconds.
+    pub(crate) fn build_body(&self) -> KucoinResults<String> {
+        if self.timeout != -1 && !(5..=86400).contains(&self.timeout) {
+            return Err(KucoinErrors::InvalidRequest(format!(
+                "DCP timeout must be -1 or between 5 and 86400 seconds, got {}",
+                self.timeout
+            )));
+        }
+        Ok(serde_json::to_string(&self)?)
+    }
+}
+
+impl FillsRequest {
+    /// Create a fills query for `symbol`, other filters unset (last 7 days).
+    pub fn new(symbol: &str) -> Self {
+        FillsRequest {
+            symbol: symbol.to_string(),
+            order_id: None,
+            side: None,
+            order_type: None,
+            last_id: None,
+            limit: None,
+            start_at: None,
+            end_at: None,
+        }
+    }
+
+    /// Only the fills of this order (Chainable).
+    pub fn set_order_id(mut self, order_id: &str) -> Self {
+        self.order_id = Some(order_id.to_string());
+        self
+    }
+
+    /// Filter by side (Chainable).
+    pub fn set_side(mut self, side: Side) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    /// Filter by order type (Chainable).
+    pub fn set_type(mut self, order_type: Type) -> Self {
+        self.order_type = Some(order_type);
+        self
+    }
+
+    /// Continue after the page that returned this `lastId` (Chainable).
+    pub fn set_last_id(mut self, last_id: i64) -> Self {
+        self.last_id = Some(last_id);
+        self
+    }
+
+    /// Set the number of fills per request (Chainable).
+    /// Note: API default 20, max 100; larger values are clamped to 100.
+    pub fn set_limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit.clamp(1, FILLS_MAX_LIMIT));
+        self
+    }
+
+    /// Set the start timestamp in milliseconds (Chainable).
+    pub fn set_start_at(mut self, start_at: i64) -> Self {
+        self.start_at = Some(start_at);
+        self
+    }
+
+    /// Set the end timestamp in milliseconds (Chainable).
+    pub fn set_end_at(mut self, end_at: i64) -> Self {
+        self.end_at = Some(end_at);
+        self
+    }
+}
+
+impl<'a> SpotHandler<'a> {
+    /// Validate `order` when the client has order validation enabled.
+    async fn check_rules(&self, order: &SpotOrderRequest) -> KucoinResults<()> {
+        if !self.client.validates_orders() {
+            return Ok(());
+        }
+        order.validate(&self.rules(&order.symbol).await?)
+    }
+
+    /// Trading rules of `symbol`, from the symbol cache when the client has one.
+    async fn rules(&self, symbol: &str) -> KucoinResults<SymbolInfo> {
+        let cached = self
+            .client
+            .symbol_cache
+            .as_ref()
+            .and_then(|cache| cache.get(symbol));
+        match cached {
+            Some(info) => Ok(info),
+            None => self.client.market().symbol(symbol).await?.into_data(),
+        }
+    }
+
+    /// Check every order against its symbol rules, looking the rules of each distinct symbol
+    /// up once (a few symbols at a time).
+    ///
+    /// # Returns
+    /// * One result per order, in order; all `Ok` when order validation is disabled. The error
+    ///   is the violation, or the failure to read the rules, as a message.
+    async fn check_rules_all(&self, orders: &[SpotOrderRequest]) -> Vec<Result<(), String>> {
+        if !self.client.validates_orders() {
+            return vec![Ok(()); orders.len()];
+        }
+        let symbols: HashSet<&str> = orders.iter().map(|order| order.symbol.as_str()).collect();
+        let rules: HashMap<&str, Result<SymbolInfo, String>> = stream::iter(symbols)
+            .map(|symbol| async move {
+                let rules = self.rules(symbol).await.map_err(|e| e.to_string());
+                (symbol, rules)
+            })
+            .buffer_unordered(SYMBOL_CONCURRENCY)
+            .collect()
+            .await;
+
+        orders
+            .iter()
+            .map(|order| match &rules[order.symbol.as_str()] {
+                Ok(info) => order.validate(info).map_err(|e| e.to_string()),
+                Err(msg) => Err(msg.clone()),
+            })
+            .collect()
+    }
+
+    /// Place a single order
+    pub async fn place_order(
+        &self,
+        order: SpotOrderRequest,
+    ) -> KucoinResults<KuCoinResponse<SpotData>> {
+        self.check_rules(&order).await?;
+        let endpoint = "/api/v1/hf/orders";
+        let body = serde_json::to_string(&order)?;
+
+        let res = self
+            .client
+            .send_mutation("POST", &body, endpoint, || {
+                KuCoinResponse::synthetic(SpotData {
+                    client_oid: order.client_oid.clone().unwrap_or_default(),
+                    order_id: dry_run_id(),
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Validate an order against the exchange without placing it.
+    /// The payload goes through the same checks as `place_order`, but nothing is matched.
+    pub async fn place_order_test(
+        &self,
+        order: SpotOrderRequest,
+    ) -> KucoinResults<KuCoinResponse<SpotData>> {
+        self.check_rules(&order).await?;
+        let endpoint = "/api/v1/hf/orders/test";
+        let body = serde_json::to_string(&order)?;
+
+        let res = self
+            .client
+            .send_mutation("POST", &body, endpoint, || {
+                KuCoinResponse::synthetic(SpotData {
+                    client_oid: order.client_oid.clone().unwrap_or_default(),
+                    order_id: dry_run_id(),
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Place any number of orders. They are split into batches of up to 20, a few batches
+    /// being sent at a time.
+    ///
+    /// # Returns
+    /// * One outcome per order, in the order they were added. An order rejected by order
+    ///   validation (see `KuCoinClient::set_validate_orders`) is failed with its violations as
+    ///   `fail_msg` and the others are still sent. When the API rejects a whole batch, each of
+    ///   its orders is failed with that error as `fail_msg`. When a batch gets no usable answer
+    ///   (transport error, unreadable or mismatched response), its orders may have been placed
+    ///   and are reported as unknown (`BatchOrderReport::unknown`); look them up with
+    ///   `order_by_client_oid` before resending.
+    pub async fn place_multi_orders(
+        &self,
+        orders: BatchSpotContract,
+    ) -> KucoinResults<BatchOrderReport> {
+        // Orders breaking the symbol rules fail on their own; the others are still sent.
+        let checks = self.check_rules_all(&orders.order_list).await;
+        let mut outcomes: Vec<(usize, BatchOrderOutcome)> = Vec::new();
+        let mut valid: Vec<(usize, SpotOrderRequest)> = Vec::new();
+        for (index, (order, check)) in orders.order_list.into_iter().zip(checks).enumerate() {
+            match check {
+                Ok(()) => valid.push((index, order)),
+                Err(msg) => outcomes.push((index, failed_outcome(order, msg))),
+            }
+        }
+
+        let batches: Vec<Vec<(usize, SpotOrderRequest)>> = valid
+            .chunks(MULTI_ORDERS_LIMIT)
+            .map(<[_]>::to_vec)
+            .collect();
+        let placed: Vec<Vec<(usize, BatchOrderOutcome)>> = stream::iter(batches)
+            .map(|batch| async move {
+                let (indices, order_list): (Vec<usize>, Vec<SpotOrderRequest>) =
+                    batch.into_iter().unzip();
+                let batch = BatchSpotContract { order_list };
+                let res = self.place_batch(&batch).await;
+                indices
+                    .into_iter()
+                    .zip(pair_batch_results(batch.order_list, res))
+                    .collect()
+            })
+            .buffered(MULTI_ORDERS_CONCURRENCY)
+            .collect()
+            .await;
+
+        outcomes.extend(placed.into_iter().flatten());
+        outcomes.sort_by_key(|(index, _)| *index);
+        Ok(BatchOrderReport {
+            outcomes: outcomes.into_iter().map(|(_, outcome)| outcome).collect(),
+        })
+    }
+
+    /// Send one batch of at most `MULTI_ORDERS_LIMIT` orders.
+    async fn place_batch(&self, orders: &BatchSpotContract) -> KucoinResults<BatchOrderResult> {
+        let endpoint = "/api/v1/hf/orders/multi";
+        let body = serde_json::to_string(orders)?;
+
+        let res = self
+            .client
+            .send_mutation("POST", &body, endpoint, || BatchOrderResult {
+                code: SUCCESS_CODE.to_string(),
+                msg: None,
+                data: orders
+                    .order_list
+                    .iter()
+                    .map(|order| SpotOrderResult {
+                        client_oid: order.client_oid.clone(),
+                        fail_msg: None,
+                        order_id: Some(dry_run_id()),
+                        success: true,
+                    })
+                    .collect(),
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Place a single order and wait for the matching result.
+    ///
+    /// # Returns
+    /// * The order status with deal and remaining sizes at the time of the response.
+    pub async fn place_order_sync(
+        &self,
+        order: SpotOrderRequest,
+    ) -> KucoinResults<KuCoinResponse<SyncOrderData>> {
+        self.check_rules(&order).await?;
+        let endpoint = "/api/v1/hf/orders/sync";
+        let body = serde_json::to_string(&order)?;
+
+        let res = self
+            .client
+            .send_mutation("POST", &body, endpoint, || {
+                KuCoinResponse::synthetic(synthetic_sync_order(&order))
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Place batch orders (up to 20) and wait for the matching result of each.
+    ///
+    /// # Returns
+    /// * `Err(KucoinErrors::InvalidRequest)` - More than 20 orders; nothing is sent.
+    /// * One result per order, in the order they were added. An order rejected by order
+    ///   validation (see `KuCoinClient::set_validate_orders`) is failed with its violations as
+    ///   `fail_msg` and the others are still sent.
+    pub async fn place_multi_orders_sync(
+        &self,
+        orders: BatchSpotContract,
+    ) -> KucoinResults<KuCoinResponse<Vec<SyncOrderResult>>> {
+        if orders.order_list.len() > MULTI_ORDERS_LIMIT {
+            return Err(KucoinErrors::InvalidRequest(format!(
+                "at most {} orders per synchronous batch, got {}",
+                MULTI_ORDERS_LIMIT,
+                orders.order_list.len()
+            )));
+        }
+
+        let checks = self.check_rules_all(&orders.order_list).await;
+        let mut results: Vec<Option<SyncOrderResult>> = Vec::new();
+        let mut valid = BatchSpotContract::new();
+        for (order, check) in orders.order_list.into_iter().zip(checks) {
+            match check {
+                Ok(()) => {
+                    results.push(None);
+                    valid = valid.add_order(order);
+                }
+                Err(msg) => results.push(Some(failed_sync_result(&order, msg))),
+            }
+        }
+        if valid.order_list.is_empty() {
+            return Ok(KuCoinResponse::synthetic(
+                results.into_iter().flatten().collect(),
+            ));
+        }
+
+        let mut res = self.place_batch_sync(&valid).await?;
+        let Some(placed) = res.data.take() else {
+            return Ok(res);
+        };
+        if placed.len() != valid.order_list.len() {
+            return Err(KucoinErrors::ApiError {
+                code: res.code,
+                msg: format!(
+                    "expected {} results, got {}",
+                    valid.order_list.len(),
+                    placed.len()
+                ),
+            });
+        }
+        // Fill the slots of the sent orders with their results, in request order.
+        let mut placed = placed.into_iter();
+        res.data = Some(
+            results
+                .into_iter()
+                .filter_map(|result| result.or_else(|| placed.next()))
+                .collect(),
+        );
+        Ok(res)
+    }
+
+    /// Send one synchronous batch of at most `MULTI_ORDERS_LIMIT` orders.
+    async fn place_batch_sync(
+        &self,
+        orders: &BatchSpotContract,
+    ) -> KucoinResults<KuCoinResponse<Vec<SyncOrderResult>>> {
+        let endpoint = "/api/v1/hf/orders/multi/sync";
+        let body = serde_json::to_string(orders)?;
+
+        let res = self
+            .client
+            .send_mutation("POST", &body, endpoint, || {
+                KuCoinResponse::synthetic(
+                    orders
+                        .order_list
+                        .iter()
+                        .map(|order| {
+                            let data = synthetic_sync_order(order);
+                            SyncOrderResult {
+                                order_id: Some(data.order_id),
+                                client_oid: data.client_oid,
+                                order_time: Some(data.order_time),
+                                origin_size: Some(data.origin_size),
+                                deal_size: Some(data.deal_size),
+                                remain_size: Some(data.remain_size),
+                                canceled_size: Some(data.canceled_size),
+                                status: Some(data.status),
+                                match_time: data.match_time,
+                                success: true,
+                                fail_msg: None,
+                            }
+                        })
+                        .collect(),
+                )
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel an order by its order id and wait for the final sizes.
+    pub async fn cancel_by_id_sync(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> KucoinResults<KuCoinResponse<SyncCancelData>> {
+        let endpoint = format!("/api/v1/hf/orders/sync/{}?symbol={}", order_id, symbol);
+        let res = self
+            .client
+            .send_mutation("DELETE", "", &endpoint, || {
+                KuCoinResponse::synthetic(synthetic_sync_cancel(Some(order_id), None))
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel an order by its client order id and wait for the final sizes.
+    pub async fn cancel_by_client_oid_sync(
+        &self,
+        symbol: &str,
+        client_oid: &str,
+    ) -> KucoinResults<KuCoinResponse<SyncCancelData>> {
+        let endpoint = format!(
+            "/api/v1/hf/orders/sync/client-order/{}?symbol={}",
+            client_oid, symbol
+        );
+        let res = self
+            .client
+            .send_mutation("DELETE", "", &endpoint, || {
+                KuCoinResponse::synthetic(synthetic_sync_cancel(None, Some(client_oid)))
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel partial order
+    pub async fn cancel_order(
+        &self,
+        req: SpotCancelRequest,
+    ) -> KucoinResults<KuCoinResponse<SpotCanceledData>> {
+        // Construct endpoint with query params
+        let endpoint = format!(
+            "/api/v1/hf/orders/cancel/{}?symbol={}&cancelSize={}",
+            req.order_id, req.symbol, req.cancel_size
+        );
+
+        let res = self
+            .client
+            .send_mutation("DELETE", "", &endpoint, || {
+                KuCoinResponse::synthetic(SpotCanceledData {
+                    cancel_size: num::parse_amount(&req.cancel_size).unwrap_or_default(),
+                    order_id: req.order_id.clone(),
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel an order entirely by its order id.
+    pub async fn cancel_by_id(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> KucoinResults<KuCoinResponse<CanceledOrder>> {
+        let endpoint = format!("/api/v1/hf/orders/{}?symbol={}", order_id, symbol);
+        let res = self
+            .client
+            .send_mutation("DELETE", "", &endpoint, || {
+                KuCoinResponse::synthetic(CanceledOrder {
+                    order_id: order_id.to_string(),
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel an order entirely by its client order id.
+    pub async fn cancel_by_client_oid(
+        &self,
+        symbol: &str,
+        client_oid: &str,
+    ) -> KucoinResults<KuCoinResponse<CanceledClientOrder>> {
+        let endpoint = format!(
+            "/api/v1/hf/orders/client-order/{}?symbol={}",
+            client_oid, symbol
+        );
+        let res = self
+            .client
+            .send_mutation("DELETE", "", &endpoint, || {
+                KuCoinResponse::synthetic(CanceledClientOrder {
+                    client_oid: client_oid.to_string(),
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel every open order of a single symbol, leaving other markets untouched.
+    ///
+    /// # Returns
+    /// * KuCoinResponse with "success" as data.
+    pub async fn cancel_all_by_symbol(
+        &self,
+        symbol: &str,
+    ) -> KucoinResults<KuCoinResponse<String>> {
+        let endpoint = format!("/api/v1/hf/orders?symbol={}", symbol);
+        let res = self
+            .client
+            .send_mutation("DELETE", "", &endpoint, || {
+                KuCoinResponse::synthetic("success".to_string())
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel the open orders of every symbol listed by `active_symbols`, a few symbols at a
+    /// time.
+    ///
+    /// A failure on one symbol does not stop the others; it is reported in
+    /// `failed_symbols` with its error.
+    pub async fn cancel_all_active(&self) -> KucoinResults<CancelAllRes> {
+        let symbols = self.active_symbols().await?.into_data()?.symbols;
+        let results: Vec<(String, KucoinResults<()>)> = stream::iter(symbols)
+            .map(|symbol| async move {
+                let res = match self.cancel_all_by_symbol(&symbol).await {
+                    Ok(res) => res.into_data().map(|_| ()),
+                    Err(e) => Err(e),
+                };
+                (symbol, res)
+            })
+            .buffered(SYMBOL_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut report = CancelAllRes {
+            failed_symbols: Vec::new(),
+            succeed_symbols: Vec::new(),
+        };
+        for (symbol, res) in results {
+            match res {
+                Ok(()) => report.succeed_symbols.push(symbol),
+                Err(e) => report.failed_symbols.push(FailedSymbol {
+                    error: Some(format!("{:?}", e)),
+                    symbol: Some(symbol),
+                }),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Modify the price and/or size of an open order in place.
+    ///
+    /// # Returns
+    /// * The new order id, or `InvalidRequest` if neither a new price nor a new size is set.
+    pub async fn modify_order(
+        &self,
+        req: SpotModifyRequest,
+    ) -> KucoinResults<KuCoinResponse<SpotModifiedData>> {
+        let endpoint = "/api/v1/hf/orders/alter";
+        let body = req.build_body()?;
+
+        let res = self
+            .client
+            .send_mutation("POST", &body, endpoint, || {
+                KuCoinResponse::synthetic(SpotModifiedData {
+                    new_order_id: dry_run_id(),
+                    client_oid: req.client_oid.clone(),
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Place a stop order. It stays untriggered, outside the order book, until the last price
+    /// reaches its stop price.
+    pub async fn place_stop_order(
+        &self,
+        order: StopOrderRequest,
+    ) -> KucoinResults<KuCoinResponse<StopOrderData>> {
+        let endpoint = "/api/v1/stop-order";
+        let body = order.build_body()?;
+
+        let res = self
+            .client
+            .send_mutation("POST", &body, endpoint, || {
+                KuCoinResponse::synthetic(StopOrderData {
+                    order_id: dry_run_id(),
+                    client_oid: order.client_oid.clone(),
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// List untriggered stop orders, one page at a time.
+    pub async fn stop_orders(
+        &self,
+        filter: StopOrderListRequest,
+    ) -> KucoinResults<KuCoinResponse<StopOrderList>> {
+        let query = serde_urlencoded::to_string(&filter)?;
+        let endpoint = if query.is_empty() {
+            "/api/v1/stop-order".to_string()
+        } else {
+            format!("/api/v1/stop-order?{}", query)
+        };
+        let res = self
+            .client
+            .send::<KuCoinResponse<StopOrderList>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get a single stop order by its order id.
+    pub async fn stop_order_by_id(
+        &self,
+        order_id: &str,
+    ) -> KucoinResults<KuCoinResponse<StopOrder>> {
+        let endpoint = format!("/api/v1/stop-order/{}", order_id);
+        let res = self
+            .client
+            .send::<KuCoinResponse<StopOrder>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get the stop orders placed with a client order id.
+    pub async fn stop_order_by_client_oid(
+        &self,
+        symbol: &str,
+        client_oid: &str,
+    ) -> KucoinResults<KuCoinResponse<Vec<StopOrder>>> {
+        let endpoint = format!(
+            "/api/v1/stop-order/queryOrderByClientOid?clientOid={}&symbol={}",
+            client_oid, symbol
+        );
+        let res = self
+            .client
+            .send::<KuCoinResponse<Vec<StopOrder>>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel an untriggered stop order by its order id.
+    pub async fn cancel_stop_order(
+        &self,
+        order_id: &str,
+    ) -> KucoinResults<KuCoinResponse<CanceledStopOrders>> {
+        let endpoint = format!("/api/v1/stop-order/{}", order_id);
+        let res = self
+            .client
+            .send_mutation("DELETE", "", &endpoint, || {
+                KuCoinResponse::synthetic(CanceledStopOrders {
+                    cancelled_order_ids: vec![order_id.to_string()],
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel an untriggered stop order by its client order id.
+    pub async fn cancel_stop_order_by_client_oid(
+        &self,
+        symbol: &str,
+        client_oid: &str,
+    ) -> KucoinResults<KuCoinResponse<CanceledStopClientOrder>> {
+        let endpoint = format!(
+            "/api/v1/stop-order/cancelOrderByClientOid?clientOid={}&symbol={}",
+            client_oid, symbol
+        );
+        let res = self
+            .client
+            .send_mutation("DELETE", "", &endpoint, || {
+                KuCoinResponse::synthetic(CanceledStopClientOrder {
+                    cancelled_order_id: dry_run_id(),
+                    client_oid: client_oid.to_string(),
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel untriggered stop orders in batch.
+    ///
+    /// # Parameters
+    /// - symbol    : Only the stop orders of this symbol, every symbol when `None`.
+    /// - order_ids : Only these order ids, every stop order when empty.
+    pub async fn cancel_stop_orders(
+        &self,
+        symbol: Option<&str>,
+        order_ids: &[&str],
+    ) -> KucoinResults<KuCoinResponse<CanceledStopOrders>> {
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if let Some(symbol) = symbol {
+            query.push(("symbol", symbol.to_string()));
+        }
+        if !order_ids.is_empty() {
+            query.push(("orderIds", order_ids.join(",")));
+        }
+        let query = serde_urlencoded::to_string(&query)?;
+        let endpoint = if query.is_empty() {
+            "/api/v1/stop-order/cancel".to_string()
+        } else {
+            format!("/api/v1/stop-order/cancel?{}", query)
+        };
+        let res = self
+            .client
+            .send_mutation("DELETE", "", &endpoint, || {
+                KuCoinResponse::synthetic(CanceledStopOrders {
+                    cancelled_order_ids: order_ids.iter().map(|id| id.to_string()).collect(),
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Set (or refresh) Dead Cancel Protection. Each call restarts the countdown; when it runs
+    /// out, the protected open HF orders are canceled by the exchange.
+    pub async fn set_dcp(&self, req: DcpRequest) -> KucoinResults<KuCoinResponse<DcpData>> {
+        let endpoint = "/api/v1/hf/orders/dead-cancel-all";
+        let body = req.build_body()?;
+
+        let res = self
+            .client
+            .send_mutation("POST", &body, endpoint, || {
+                let now = time::get_timestamp().parse::<i64>().unwrap_or_default() / 1000;
+                KuCoinResponse::synthetic(DcpData {
+                    current_time: now,
+                    trigger_time: now + req.timeout.max(0),
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Current Dead Cancel Protection settings. `data` is `None` when DCP is not set.
+    pub async fn dcp(&self) -> KucoinResults<KuCoinResponse<DcpSettings>> {
+        let endpoint = "/api/v1/hf/orders/dead-cancel-all/query";
+        let res = self
+            .client
+            .send::<KuCoinResponse<DcpSettings>>("GET", "", endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get open orders
+    pub async fn list_orders_open(
+        &self,
+        ticker: &str,
+    ) -> KucoinResults<KuCoinResponse<Vec<SpotDatum>>> {
+        let endpoint = format!("/api/v1/hf/orders/active?symbol={}", ticker);
+        let res = self
+            .client
+            .send::<KuCoinResponse<Vec<SpotDatum>>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get one page of closed (filled or canceled) orders.
+    pub async fn list_orders_done(
+        &self,
+        filter: DoneOrdersRequest,
+    ) -> KucoinResults<KuCoinResponse<DoneOrderList>> {
+        let query = serde_urlencoded::to_string(&filter)?;
+        let endpoint = format!("/api/v1/hf/orders/done?{}", query);
+        let res = self
+            .client
+            .send::<KuCoinResponse<DoneOrderList>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Every closed order matching `filter`, newest first.
+    ///
+    /// A `start_at` / `end_at` range wider than 7 days is split into 7-day windows, each
+    /// followed through the `lastId` cursor. Without `start_at` only the API default range
+    /// (the last 7 days) is covered; a missing `end_at` means now. A `last_id` only applies to
+    /// the first (latest) window.
+    ///
+    /// # Returns
+    /// * A stream yielding a single `Err(KucoinErrors::InvalidRequest)` when `start_at` is after
+    ///   `end_at`.
+    pub fn done_orders_stream(
+        &self,
+        filter: DoneOrdersRequest,
+    ) -> impl Stream<Item = KucoinResults<SpotDatum>> + 'a {
+        let client = self.client;
+        let windows = match filter.start_at {
+            Some(start) => {
+                let end = filter
+                    .end_at
+                    .unwrap_or_else(|| time::get_timestamp().parse().unwrap_or(start));
+                if start > end {
+                    vec![Err(KucoinErrors::InvalidRequest(format!(
+                        "start_at {} is after end_at {}",
+                        start, end
+                    )))]
+                } else {
+                    split_window(start, end, DONE_ORDERS_MAX_WINDOW)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, (start, end))| {
+                            let mut window = filter.clone().set_start_at(start).set_end_at(end);
+                            if i > 0 {
+                                window.last_id = None;
+                            }
+                            Ok(window)
+                        })
+                        .collect()
+                }
+            }
+            None => vec![Ok(filter)],
+        };
+
+        stream::iter(windows)
+            .map(move |window| match window {
+                Ok(window) => done_orders_pages(client, window).left_stream(),
+                Err(e) => stream::once(async move { Err(e) }).right_stream(),
+            })
+            .flatten()
+    }
+
+    /// Get one page of trade fills.
+    pub async fn fills(&self, filter: FillsRequest) -> KucoinResults<KuCoinResponse<FillList>> {
+        let query = serde_urlencoded::to_string(&filter)?;
+        let endpoint = format!("/api/v1/hf/fills?{}", query);
+        let res = self
+            .client
+            .send::<KuCoinResponse<FillList>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Follow the `lastId` cursor through every fill matching `filter`, newest first.
+    pub fn fills_stream(
+        &self,
+        filter: FillsRequest,
+    ) -> impl Stream<Item = KucoinResults<Fill>> + 'a {
+        let client = self.client;
+        // A page shorter than the limit is the last one; the server caps pages at 100.
+        let limit = filter
+            .limit
+            .unwrap_or(FILLS_LIMIT)
+            .clamp(1, FILLS_MAX_LIMIT);
+
+        stream::try_unfold(Some(filter), move |next| async move {
+            let Some(filter) = next else {
+                return Ok::<_, KucoinErrors>(None);
+            };
+            let page = client.spot().fills(filter.clone()).await?.into_data()?;
+            let following = match page.last_id {
+                Some(last_id) if page.items.len() as i64 >= limit => {
+                    Some(filter.set_last_id(last_id))
+                }
+                _ => None,
+            };
+            Ok(Some((page.items, following)))
+        })
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// List the symbols that currently have open HF orders.
+    pub async fn active_symbols(&self) -> KucoinResults<KuCoinResponse<ActiveSymbols>> {
+        let endpoint = "/api/v1/hf/orders/active/symbols";
+        let res = self
+            .client
+            .send::<KuCoinResponse<ActiveSymbols>>("GET", "", endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Open orders of every symbol listed by `active_symbols`, a few symbols fetched at a time.
+    pub async fn open_orders_all(&self) -> KucoinResults<OpenOrders> {
+        let symbols = self.active_symbols().await?.into_data()?.symbols;
+        let per_symbol: Vec<(String, Vec<SpotDatum>)> = stream::iter(symbols)
+            .map(|symbol| async move {
+                let orders = self.list_orders_open(&symbol).await?.into_data()?;
+                Ok::<_, KucoinErrors>((symbol, orders))
+            })
+            .buffer_unordered(SYMBOL_CONCURRENCY)
+            .try_collect()
+            .await?;
+        let by_symbol = per_symbol
+            .into_iter()
+            .filter(|(_, orders)| !orders.is_empty())
+            .collect();
+        Ok(OpenOrders { by_symbol })
+    }
+
+    /// Get a single order by its order id, active or done.
+    pub async fn order_by_id(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> KucoinResults<KuCoinResponse<SpotDatum>> {
+        let endpoint = format!("/api/v1/hf/orders/{}?symbol={}", order_id, symbol);
+        let res = self
+            .client
+            .send::<KuCoinResponse<SpotDatum>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Get a single order by its client order id, active or done.
+    pub async fn order_by_client_oid(
+        &self,
+        symbol: &str,
+        client_oid: &str,
+    ) -> KucoinResults<KuCoinResponse<SpotDatum>> {
+        let endpoint = format!(
+            "/api/v1/hf/orders/client-order/{}?symbol={}",
+            client_oid, symbol
+        );
+        let res = self
+            .client
+            .send::<KuCoinResponse<SpotDatum>>("GET", "", &endpoint)
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel every open order across all symbols.
+    pub async fn close_all(&self) -> KucoinResults<KuCoinResponse<CancelAllRes>> {
+        let endpoint = format!("/api/v1/hf/orders/cancelAll");
+        let res = self
+            .client
+            .send_mutation("DELETE", "", &endpoint, || {
+                KuCoinResponse::synthetic(CancelAllRes {
+                    failed_symbols: Vec::new(),
+                    succeed_symbols: Vec::new(),
+                })
+            })
+            .await?;
+        Ok(res)
+    }
+}
+
+/// Follow the `lastId` cursor through one time window of closed orders.
+fn done_orders_pages<'a>(
+    client: &'a KuCoinClient,
+    filter: DoneOrdersRequest,
+) -> impl Stream<Item = KucoinResults<SpotDatum>> + 'a {
+    // A page shorter than the limit is the last one; the server caps pages at 100.
+    let limit = filter
+        .limit
+        .unwrap_or(DONE_ORDERS_LIMIT)
+        .clamp(1, DONE_ORDERS_MAX_LIMIT);
+
+    stream::try_unfold(Some(filter), move |next| async move {
+        let Some(filter) = next else {
+            return Ok::<_, KucoinErrors>(None);
+        };
+        let page = client
+            .spot()
+            .list_orders_done(filter.clone())
+            .await?
+            .into_data()?;
+        let following = match page.last_id {
+            Some(last_id) if page.items.len() as i64 >= limit => Some(filter.set_last_id(last_id)),
+            _ => None,
+        };
+        Ok(Some((page.items, following)))
+    })
+    .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+/// Split `[start, end]` into disjoint inclusive windows no wider than `max`, latest window
+/// first. Windows do not share a boundary, so no record is returned by two of them.
+fn split_window(start: i64, end: i64, max: i64) -> Vec<(i64, i64)> {
+    let mut windows = Vec::new();
+    let mut upper = end;
+    loop {
+        let lower = (upper - max).max(start);
+        windows.push((lower, upper));
+        if lower <= start {
+            break;
+        }
+        upper = lower - 1;
+    }
+    windows
+}
+
+/// Pair each order of a batch with its result. The API answers in request order.
+///
+/// A batch rejected by the API, or that could not be sent at all, fails each of its orders. A
+/// batch whose answer is lost, unreadable or does not line up with the request may still have
+/// been placed, so its orders are reported as unknown rather than failed.
+fn pair_batch_results(
+    orders: Vec<SpotOrderRequest>,
+    res: KucoinResults<BatchOrderResult>,
+) -> Vec<BatchOrderOutcome> {
+    let batch = match res {
+        Ok(batch) if batch.code != SUCCESS_CODE => {
+            let msg = KucoinErrors::ApiError {
+                code: batch.code,
+                msg: batch.msg.unwrap_or_default(),
+            }
+            .to_string();
+            return orders
+                .into_iter()
+                .map(|request| failed_outcome(request, msg.clone()))
+                .collect();
+        }
+        Ok(batch) if batch.data.len() != orders.len() => {
+            let msg = format!(
+                "expected {} results, got {}",
+                orders.len(),
+                batch.data.len()
+            );
+            return unknown_outcomes(orders, msg);
+        }
+        Ok(batch) => batch,
+        Err(e) if !was_sent(&e) => {
+            let msg = e.to_string();
+            return orders
+                .into_iter()
+                .map(|request| failed_outcome(request, msg.clone()))
+                .collect();
+        }
+        Err(e) => return unknown_outcomes(orders, e.to_string()),
+    };
+
+    orders
+        .into_iter()
+        .zip(batch.data)
+        .map(|(request, result)| BatchOrderOutcome {
+            request,
+            result: Some(result),
+            unknown_reason: None,
+        })
+        .collect()
+}
+
+/// Whether a request that failed with `e` may have reached the exchange.
+fn was_sent(e: &KucoinErrors) -> bool {
+    match e {
+        KucoinErrors::ReqwestError(e) => !(e.is_connect() || e.is_builder()),
+        KucoinErrors::JSONError(_)
+        | KucoinErrors::UrlEncodeError(_)
+        | KucoinErrors::InvalidRequest(_) => false,
+        _ => true,
+    }
+}
+
+/// An order that was not placed, with the reason as `fail_msg`.
+fn failed_outcome(request: SpotOrderRequest, msg: String) -> BatchOrderOutcome {
+    BatchOrderOutcome {
+        result: Some(SpotOrderResult {
+            client_oid: request.client_oid.clone(),
+            fail_msg: Some(msg),
+            order_id: None,
+            success: false,
+        }),
+        unknown_reason: None,
+        request,
+    }
+}
+
+/// Orders of a batch whose answer was lost, with the reason.
+fn unknown_outcomes(orders: Vec<SpotOrderRequest>, msg: String) -> Vec<BatchOrderOutcome> {
+    orders
+        .into_iter()
+        .map(|request| BatchOrderOutcome {
+            request,
+            result: None,
+            unknown_reason: Some(msg.clone()),
+        })
+        .collect()
+}
+
+/// A synchronous batch order that was not placed, with the reason as `fail_msg`.
+fn failed_sync_result(request: &SpotOrderRequest, msg: String) -> SyncOrderResult {
+    SyncOrderResult {
+        order_id: None,
+        client_oid: request.client_oid.clone(),
+        order_time: None,
+        origin_size: None,
+        deal_size: None,
+        remain_size: None,
+        canceled_size: None,
+        status: None,
+        match_time: None,
+        success: false,
+        fail_msg: Some(msg),
+    }
+}
+
+/// Dry-run result of a synchronous order: accepted, nothing filled yet.
+fn synthetic_sync_order(order: &SpotOrderRequest) -> SyncOrderData {
+    let size = order
+        .size
+        .as_deref()
+        .and_then(num::parse_amount)
+        .unwrap_or_default();
+    SyncOrderData {
+        order_id: dry_run_id(),
+        client_oid: order.client_oid.clone(),
+        order_time: time::get_timestamp().parse().unwrap_or_default(),
+        origin_size: size,
+        deal_size: Amount::ZERO,
+        remain_size: size,
+        canceled_size: Amount::ZERO,
+        status: OrderStatus::Open,
+        match_time: None,
+    }
+}
+
+/// Dry-run result of a synchronous cancellation. Sizes are unknown without a query.
+fn synthetic_sync_cancel(order_id: Option<&str>, client_oid: Option<&str>) -> SyncCancelData {
+    SyncCancelData {
+        order_id: order_id.map(str::to_string),
+        client_oid: client_oid.map(str::to_string),
+        origin_size: Amount::ZERO,
+        deal_size: Amount::ZERO,
+        remain_size: Amount::ZERO,
+        canceled_size: Amount::ZERO,
+        status: OrderStatus::Done,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        client::{
+            fixtures::{amt, btc_rules},
+            mock::MockServer,
+            rest::Credentials,
+        },
+        types::{DRY_RUN_PREFIX, spot::Liquidity},
+    };
+
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_split_window() {
+        let day = 24 * 60 * 60 * 1000;
+        assert_eq!(split_window(0, 3 * day, 7 * day), vec![(0, 3 * day)]);
+        assert_eq!(
+            split_window(0, 15 * day, 7 * day),
+            vec![(8 * day, 15 * day), (day - 1, 8 * day - 1), (0, day - 2)]
+        );
+        assert_eq!(split_window(5, 5, 7 * day), vec![(5, 5)]);
+
+        // Consecutive windows neither overlap nor leave a gap.
+        let windows = split_window(0, 30 * day + 123, 7 * day);
+        for pair in windows.windows(2) {
+            let (later, earlier) = (pair[0], pair[1]);
+            assert_eq!(earlier.1 + 1, later.0);
+            assert!(later.1 - later.0 <= 7 * day);
+        }
+        assert_eq!(windows.last().unwrap().0, 0);
+    }
+
+    #[tokio::test]
+    async fn test_done_orders_stream_windows() {
+        let server = MockServer::start(|_| r#"{"code":"200000","data":{"items":[]}}"#.to_string());
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client.base_link = server.url.clone();
+        let day = 24 * 60 * 60 * 1000;
+
+        let inverted = DoneOrdersRequest::new("BTC-USDT")
+            .set_start_at(2 * day)
+            .set_end_at(day);
+        let res: Vec<_> = client.spot().done_orders_stream(inverted).collect().await;
+        assert!(matches!(res[..], [Err(KucoinErrors::InvalidRequest(_))]));
+        assert!(server.requests().is_empty());
+
+        // The caller's cursor only continues the latest window.
+        let filter = DoneOrdersRequest::new("BTC-USDT")
+            .set_last_id(42)
+            .set_start_at(0)
+            .set_end_at(15 * day);
+        let orders: Vec<_> = client
+            .spot()
+            .done_orders_stream(filter)
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(orders.is_empty());
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].contains("lastId=42"));
+        assert!(requests[1..].iter().all(|r| !r.contains("lastId")));
+    }
+
+    #[test]
+    fn test_done_orders_limit_clamped() {
+        let filter = DoneOrdersRequest::new("BTC-USDT").set_limit(200);
+        assert_eq!(filter.limit, Some(DONE_ORDERS_MAX_LIMIT));
+    }
+
+    #[test]
+    fn test_fills_limit_clamped() {
+        let filter = FillsRequest::new("BTC-USDT").set_limit(200);
+        assert_eq!(filter.limit, Some(FILLS_MAX_LIMIT));
+    }
+
+    #[test]
+    fn test_done_orders_query() {
+        let filter = DoneOrdersRequest::new("BTC-USDT")
+            .set_side(Side::Sell)
+            .set_type(Type::Limit)
+            .set_last_id(254062248624417);
+        assert_eq!(
+            serde_urlencoded::to_string(&filter).unwrap(),
+            "symbol=BTC-USDT&side=sell&type=limit&lastId=254062248624417"
+        );
+    }
+
+    #[test]
+    fn test_parse_fills() {
+        let json = r#"{"code":"200000","data":{"items":[{"id":19814995255305,"orderId":"6717422bd51c29000775ea03","counterOrderId":"67174228135f9e000709da8c","tradeId":11029373945659392,"symbol":"BTC-USDT","side":"buy","liquidity":"taker","type":"limit","forceTaker":false,"price":"67717.6","size":"0.00001","funds":"0.677176","fee":"0.000677176","feeRate":"0.001","feeCurrency":"USDT","stop":"","tradeType":"TRADE","taxRate":"0","tax":"0","createdAt":1729577515473}],"lastId":19814995255305}}"#;
+        let res: KuCoinResponse<FillList> = serde_json::from_str(json).unwrap();
+        let page = res.into_data().unwrap();
+        let fill = &page.items[0];
+
+        assert_eq!(page.last_id, Some(19814995255305));
+        assert_eq!(fill.trade_id, 11029373945659392);
+        assert_eq!(fill.liquidity, Liquidity::Taker);
+        assert_eq!(fill.price, amt("67717.6"));
+        assert_eq!(fill.fee, amt("0.000677176"));
+        assert_eq!(fill.tax, Some(amt("0")));
+    }
+
+    #[test]
+    fn test_stop_order_body() {
+        let market = StopOrderRequest::new(
+            TradeType::Market,
+            "BTC-USDT",
+            Side::Sell,
+            StopType::Loss,
+            60000.0,
+        );
+        assert!(market.build_body().is_err());
+        assert!(market.clone().set_size(0.01).build_body().is_ok());
+        assert!(market.set_size(0.01).set_funds(100.0).build_body().is_err());
+
+        let limit = StopOrderRequest::new(
+            TradeType::Limit,
+            "BTC-USDT",
+            Side::Buy,
+            StopType::Entry,
+            70000.0,
+        )
+        .set_size(0.01);
+        assert!(limit.build_body().is_err());
+        let body = limit.set_price(70100.0).build_body().unwrap();
+        assert!(body.contains(r#""stop":"entry","stopPrice":"70000""#));
+    }
+
+    #[test]
+    fn test_dcp_timeout() {
+        assert!(DcpRequest::new(4).build_body().is_err());
+        assert!(DcpRequest::new(86401).build_body().is_err());
+        assert!(DcpRequest::disable().build_body().is_ok());
+        assert_eq!(
+            DcpRequest::new(30)
+                .set_symbols(&["BTC-USDT", "ETH-USDT"])
+                .build_body()
+                .unwrap(),
+            r#"{"timeout":30,"symbols":"BTC-USDT,ETH-USDT"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dcp_keepalive_validates() {
+        let client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        let every = std::time::Duration::from_secs(10);
+
+        assert!(matches!(
+            client.spawn_dcp_keepalive(DcpRequest::new(4), every),
+            Err(KucoinErrors::InvalidRequest(_))
+        ));
+        assert!(matches!(
+            client.spawn_dcp_keepalive(DcpRequest::new(30), std::time::Duration::ZERO),
+            Err(KucoinErrors::InvalidRequest(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_order() {
+        let rules = btc_rules();
+
+        let ok = SpotOrderRequest::new(TradeType::Limit, "BTC-USDT", Side::Buy)
+            .set_price(67000.1)
+            .set_size(0.001);
+        assert!(ok.validate(&rules).is_ok());
+
+        let bad = SpotOrderRequest::new(TradeType::Limit, "BTC-USDT", Side::Buy)
+            .set_price(67000.05)
+            .set_size(0.000001)
+            .set_iceberg(true);
+        let Err(KucoinErrors::OrderValidation(violations)) = bad.validate(&rules) else {
+            panic!("expected violations");
+        };
+        assert!(violations.contains(&OrderViolation::MissingVisibleSize));
+        assert!(
+            violations
+                .iter()
+                .any(|v| matches!(v, OrderViolation::NotIncrement { field: "price", .. }))
+        );
+        assert!(
+            violations
+                .iter()
+                .any(|v| matches!(v, OrderViolation::BelowMin { field: "size", .. }))
+        );
+
+        let market = SpotOrderRequest::new(TradeType::Market, "BTC-USDT", Side::Buy);
+        let Err(KucoinErrors::OrderValidation(violations)) = market.validate(&rules) else {
+            panic!("expected violations");
+        };
+        assert_eq!(violations, vec![OrderViolation::SizeOrFunds]);
+        assert!(market.set_funds(100.0).validate(&rules).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_auto_validation() {
+        let cache = crate::client::cache::SymbolCache::new();
+        cache.update(vec![btc_rules()]);
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client
+            .set_dry_run(true)
+            .set_validate_orders(true)
+            .set_symbol_cache(cache);
+
+        let order = SpotOrderRequest::new(TradeType::Limit, "BTC-USDT", Side::Buy).set_price(1.0);
+        assert!(matches!(
+            client.spot().place_order(order).await,
+            Err(KucoinErrors::OrderValidation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_multi_orders_partial_validation() {
+        let cache = crate::client::cache::SymbolCache::new();
+        cache.update(vec![btc_rules()]);
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client
+            .set_dry_run(true)
+            .set_validate_orders(true)
+            .set_symbol_cache(cache);
+
+        let valid = || {
+            SpotOrderRequest::new(TradeType::Limit, "BTC-USDT", Side::Buy)
+                .set_price(10000.0)
+                .set_size(0.001)
+        };
+        let orders = BatchSpotContract::new()
+            .add_order(valid())
+            .add_order(
+                SpotOrderRequest::new(TradeType::Limit, "BTC-USDT", Side::Buy).set_price(1.05),
+            )
+            .add_order(valid());
+
+        let report = client.spot().place_multi_orders(orders).await.unwrap();
+        assert_eq!(report.len(), 3);
+        assert_eq!(report.succeeded().count(), 2);
+        assert!(report.outcomes[0].is_placed());
+        assert!(report.outcomes[1].is_rejected());
+        assert!(
+            report.outcomes[1]
+                .result
+                .as_ref()
+                .unwrap()
+                .fail_msg
+                .as_deref()
+                .unwrap()
+                .contains("symbol rules")
+        );
+        assert!(report.outcomes[2].is_placed());
+    }
+
+    #[tokio::test]
+    async fn test_multi_orders_fetch_rules_once_per_symbol() {
+        let rules = serde_json::to_string(&btc_rules()).unwrap();
+        let server = MockServer::start(move |target| {
+            if target == "/api/v2/symbols/BTC-USDT" {
+                format!(r#"{{"code":"200000","data":{}}}"#, rules)
+            } else {
+                r#"{"code":"400100","msg":"symbol not exists"}"#.to_string()
+            }
+        });
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client.base_link = server.url.clone();
+        client.set_dry_run(true).set_validate_orders(true);
+
+        let mut orders = BatchSpotContract::new();
+        for _ in 0..45 {
+            orders = orders.add_order(
+                SpotOrderRequest::new(TradeType::Limit, "BTC-USDT", Side::Buy)
+                    .set_price(10000.0)
+                    .set_size(0.001),
+            );
+        }
+        orders = orders.add_order(
+            SpotOrderRequest::new(TradeType::Limit, "NOPE-USDT", Side::Buy)
+                .set_price(1.0)
+                .set_size(1.0),
+        );
+
+        let report = client.spot().place_multi_orders(orders).await.unwrap();
+        assert_eq!(report.succeeded().count(), 45);
+        assert!(report.outcomes[45].is_rejected());
+
+        let mut requests = server.requests();
+        requests.sort();
+        assert_eq!(
+            requests,
+            vec!["/api/v2/symbols/BTC-USDT", "/api/v2/symbols/NOPE-USDT"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_multi_orders_sync_partial_validation() {
+        let cache = crate::client::cache::SymbolCache::new();
+        cache.update(vec![btc_rules()]);
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client
+            .set_dry_run(true)
+            .set_validate_orders(true)
+            .set_symbol_cache(cache);
+
+        let valid = || {
+            SpotOrderRequest::new(TradeType::Limit, "BTC-USDT", Side::Buy)
+                .set_price(10000.0)
+                .set_size(0.001)
+        };
+        let orders = BatchSpotContract::new()
+            .add_order(
+                SpotOrderRequest::new(TradeType::Limit, "BTC-USDT", Side::Buy).set_price(1.05),
+            )
+            .add_order(valid());
+        let results = client
+            .spot()
+            .place_multi_orders_sync(orders)
+            .await
+            .unwrap()
+            .into_data()
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(!results[0].success);
+        assert!(results[0].fail_msg.is_some());
+        assert!(results[1].success);
+
+        let mut orders = BatchSpotContract::new();
+        for _ in 0..=MULTI_ORDERS_LIMIT {
+            orders = orders.add_order(valid());
+        }
+        assert!(matches!(
+            client.spot().place_multi_orders_sync(orders).await,
+            Err(KucoinErrors::InvalidRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_multi_orders_garbled_batch_unknown() {
+        // The 5-order batch gets a truncated body; the 20-order batch a normal answer.
+        let server = MockServer::start_with_body(|_, body| {
+            let count = body.matches("clientOid").count();
+            if count == 5 {
+                return r#"{"code":"200000","data":[{"orderId":"1","#.to_string();
+            }
+            let results = vec![r#"{"orderId":"1","clientOid":"x","success":true}"#; count];
+            format!(r#"{{"code":"200000","data":[{}]}}"#, results.join(","))
+        });
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client.base_link = server.url.clone();
+
+        let orders = (0..25).fold(BatchSpotContract::new(), |batch, _| {
+            batch.add_order(
+                SpotOrderRequest::new(TradeType::Limit, "BTC-USDT", Side::Buy)
+                    .set_price(10000.0)
+                    .set_size(0.001),
+            )
+        });
+        let report = client.spot().place_multi_orders(orders).await.unwrap();
+
+        assert_eq!(server.requests().len(), 2);
+        assert_eq!(report.succeeded().count(), 20);
+        assert_eq!(report.failed().count(), 0);
+        assert_eq!(report.unknown().count(), 5);
+        assert!(
+            report.outcomes[20..]
+                .iter()
+                .all(|o| o.unknown_reason.is_some())
+        );
+        assert!(!report.all_succeeded());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_order() {
+        // Dummy credentials: nothing leaves the process in dry-run mode.
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client.set_dry_run(true);
+
+        let order = SpotOrderRequest::new(TradeType::Limit, "BTC-USDT", Side::Buy)
+            .set_price(10000.0)
+            .set_size(0.001);
+        let client_oid = order.client_oid.clone().unwrap();
+
+        let data = client
+            .spot()
+            .place_order(order)
+            .await
+            .unwrap()
+            .into_data()
+            .unwrap();
+        assert_eq!(data.client_oid, client_oid);
+        assert!(data.order_id.starts_with(DRY_RUN_PREFIX));
+
+        let cancel = client
+            .spot()
+            .cancel_by_id_sync("BTC-USDT", &data.order_id)
+            .await
+            .unwrap()
+            .into_data()
+            .unwrap();
+        assert_eq!(cancel.status, OrderStatus::Done);
+    }
+
+    #[test]
+    fn test_pair_batch_results() {
+        let orders = vec![
+            SpotOrderRequest::new(TradeType::Limit, "BTC-USDT", Side::Buy),
+            SpotOrderRequest::new(TradeType::Limit, "ETH-USDT", Side::Buy),
+        ];
+        let json = r#"{"code":"200000","data":[
+            {"orderId":"1","clientOid":"a","success":true},
+            {"success":false,"failMsg":"The order funds should more then 0.1 USDT."}]}"#;
+        let batch: BatchOrderResult = serde_json::from_str(json).unwrap();
+        let outcomes = pair_batch_results(orders.clone(), Ok(batch));
+        assert_eq!(outcomes[0].request.symbol, "BTC-USDT");
+        assert!(outcomes[0].is_placed());
+        assert_eq!(outcomes[1].request.symbol, "ETH-USDT");
+        assert!(
+            outcomes[1]
+                .result
+                .as_ref()
+                .unwrap()
+                .fail_msg
+                .as_deref()
+                .unwrap()
+                .contains("0.1 USDT")
+        );
+
+        let rejected: BatchOrderResult =
+            serde_json::from_str(r#"{"code":"400100","msg":"too many orders"}"#).unwrap();
+        let outcomes = pair_batch_results(orders.clone(), Ok(rejected));
+        assert!(outcomes.iter().all(BatchOrderOutcome::is_rejected));
+        assert!(
+            outcomes[1]
+                .result
+                .as_ref()
+                .unwrap()
+                .fail_msg
+                .as_deref()
+                .unwrap()
+                .contains("too many orders")
+        );
+        assert_eq!(
+            outcomes[1].result.as_ref().unwrap().client_oid,
+            orders[1].client_oid
+        );
+
+        let err = KucoinErrors::InvalidRequest("boom".to_string());
+        let outcomes = pair_batch_results(orders.clone(), Err(err));
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(BatchOrderOutcome::is_rejected));
+
+        // A short answer may hide placed orders: report them as unknown, not failed.
+        let short: BatchOrderResult = serde_json::from_str(
+            r#"{"code":"200000","data":[{"orderId":"1","clientOid":"a","success":true}]}"#,
+        )
+        .unwrap();
+        let outcomes = pair_batch_results(orders, Ok(short));
+        assert!(outcomes.iter().all(BatchOrderOutcome::is_unknown));
+        assert!(
+            outcomes[0]
+                .unknown_reason
+                .as_deref()
+                .unwrap()
+                .contains("got 1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_multi_orders_chunked() {
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client.set_dry_run(true);
+
+        let orders = (0..45).fold(BatchSpotContract::new(), |batch, i| {
+            batch.add_order(
+                SpotOrderRequest::new(TradeType::Limit, "BTC-USDT", Side::Buy)
+                    .set_price(10000.0 + i as f64)
+                    .set_size(0.001),
+            )
+        });
+        let client_oids: Vec<_> = orders
+            .order_list
+            .iter()
+            .map(|order| order.client_oid.clone())
+            .collect();
+
+        let report = client.spot().place_multi_orders(orders).await.unwrap();
+        assert_eq!(report.len(), 45);
+        assert!(report.all_succeeded());
+        let placed: Vec<_> = report
+            .outcomes
+            .iter()
+            .map(|outcome| outcome.result.as_ref().unwrap().client_oid.clone())
+            .collect();
+        assert_eq!(placed, client_oids);
+    }
+
+    #[tokio::test]
+    async fn test_send_order() {
+        // 1. Setup Credentials
+        let credentials = Credentials::new(
+            &env::var("api_key").unwrap(),
+            &env::var("api_secret").unwrap(),
+            &env::var("api_passphrase").unwrap(),
+        );
+
+        // 2. Initialize Client
+        let client = KuCoinClient::new(credentials);
+
+        // 3. Generate SpotContract.
+        let open_long_btc = SpotOrderRequest::new(TradeType::Market, "BTC-USDT", Side::Buy)
+            .set_funds(0.0)
+            .set_remark("syndicate");
+
+        // 4. Execute.
+        match client.spot().place_order(open_long_btc).await {
+            Ok(res) => println!("Trade Order: {:#?}", res),
+            Err(e) => println!("Err: {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_multi_orders() {
+        // 1. Setup Credentials
+        let credentials = Credentials::new(
+            &env::var("api_key").unwrap(),
+            &env::var("api_secret").unwrap(),
+            &env::var("api_passphrase").unwrap(),
+        );
+
+        // 2. Initialize Client
+        let client = KuCoinClient::new(credentials);
+
+        // 3. Generate SpotContracts.
+        let btc_contract = SpotOrderRequest::new(TradeType::Market, "BTC-USDT", Side::Buy)
+            .set_funds(0.0)
+            .set_remark("syndicate");
+        let sol_contract = SpotOrderRequest::new(TradeType::Market, "SOL-USDT", Side::Buy)
+            .set_funds(0.0)
+            .set_remark("syndicate2");
+
+        let orders = BatchSpotContract::new()
+            .add_order(btc_contract)
+            .add_order(sol_contract);
+
+        // 4. Execute
+        match client.spot().place_multi_orders(orders).await {
+            Ok(res) => println!("Trade Orders: {:#?}", res),
+            Err(e) => println!("Multi Orders Err: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_modify_request_validation() {
+        let empty = SpotModifyRequest::by_order_id("BTC-USDT", "670fd33bf9406e0007ab3945");
+        assert!(matches!(
+            empty.build_body(),
+            Err(KucoinErrors::InvalidRequest(_))
+        ));
+
+        let body = SpotModifyRequest::by_client_oid("BTC-USDT", "my-oid")
+            .set_new_price(65000.5)
+            .build_body()
+            .unwrap();
+        assert_eq!(
+            body,
+            r#"{"symbol":"BTC-USDT","clientOid":"my-oid","newPrice":"65000.5"}"#
+        );
+    }
+
+    #[test]
+    fn test_parse_sync_order() {
+        let json = r#"{"code":"200000","data":{"orderId":"67111a7cb7cbdf000703e1f6","clientOid":"5c52e11203aa677f33e493fb","orderTime":1729174140586,"originSize":"0.00001","dealSize":"0.00001","remainSize":"0","canceledSize":"0","status":"done","matchTime":1729174140588}}"#;
+        let res: KuCoinResponse<SyncOrderData> = serde_json::from_str(json).unwrap();
+        let order = res.into_data().unwrap();
+
+        assert_eq!(order.status, OrderStatus::Done);
+        assert_eq!(order.deal_size, amt("0.00001"));
+    }
+
+    #[test]
+    fn test_parse_done_order() {
+        let json = r#"{"code":"200000","data":{"id":"6717422bd51c29000775ea03","clientOid":"5c52e11203aa677f33e493fb","symbol":"BTC-USDT","opType":"DEAL","type":"limit","side":"buy","price":"70000","size":"0.00001","funds":"0.7","dealSize":"0.00001","dealFunds":"0.677176","remainSize":"0","remainFunds":"0.022824","cancelledSize":"0","cancelledFunds":"0","fee":"0.000677176","feeCurrency":"USDT","stp":null,"timeInForce":"GTC","postOnly":false,"hidden":false,"iceberg":false,"visibleSize":"0","cancelAfter":0,"channel":"API","remark":"order remarks","tags":null,"cancelExist":false,"tradeType":"TRADE","inOrderBook":false,"active":false,"tax":"0","createdAt":1729577515444,"lastUpdatedAt":1729577515481}}"#;
+        let res: KuCoinResponse<SpotDatum> = serde_json::from_str(json).unwrap();
+        let order = res.into_data().unwrap();
+
+        assert!(!order.active);
+        assert_eq!(order.deal_size, amt("0.00001"));
+        assert_eq!(order.remain_size, amt("0"));
+    }
+
+    #[tokio::test]
+    async fn test_active_symbols_fan_out() {
+        let order = r#"{"id":"6717422bd51c29000775ea03","clientOid":"5c52e11203aa677f33e493fb","symbol":"BTC-USDT","opType":"DEAL","type":"limit","side":"buy","price":"70000","size":"0.00001","funds":"0.7","dealSize":"0","dealFunds":"0","remainSize":"0.00001","remainFunds":"0.7","cancelledSize":"0","cancelledFunds":"0","fee":"0","feeCurrency":"USDT","stp":null,"timeInForce":"GTC","postOnly":false,"hidden":false,"iceberg":false,"visibleSize":"0","cancelAfter":0,"channel":"API","remark":null,"tags":null,"cancelExist":false,"tradeType":"TRADE","inOrderBook":true,"active":true,"tax":"0","createdAt":1729577515444,"lastUpdatedAt":1729577515481}"#;
+        let server = MockServer::start(move |target| match target {
+            "/api/v1/hf/orders/active/symbols" => {
+                r#"{"code":"200000","data":{"symbols":["BTC-USDT","ETH-USDT","SOL-USDT"]}}"#
+                    .to_string()
+            }
+            "/api/v1/hf/orders/active?symbol=BTC-USDT" => {
+                format!(r#"{{"code":"200000","data":[{}]}}"#, order)
+            }
+            "/api/v1/hf/orders?symbol=SOL-USDT" => {
+                r#"{"code":"400100","msg":"system timeout"}"#.to_string()
+            }
+            target if target.starts_with("/api/v1/hf/orders/active?") => {
+                r#"{"code":"200000","data":[]}"#.to_string()
+            }
+            _ => r#"{"code":"200000","data":"success"}"#.to_string(),
+        });
+        let mut client = KuCoinClient::new(Credentials::new("key", "secret", "pass"));
+        client.base_link = server.url.clone();
+
+        let open = client.spot().open_orders_all().await.unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open.symbols().collect::<Vec<_>>(), vec!["BTC-USDT"]);
+
+        let report = client.spot().cancel_all_active().await.unwrap();
+        assert_eq!(report.succeed_symbols, vec!["BTC-USDT", "ETH-USDT"]);
+        assert_eq!(report.failed_symbols.len(), 1);
+        assert_eq!(report.failed_symbols[0].symbol.as_deref(), Some("SOL-USDT"));
+        assert!(
+            report.failed_symbols[0]
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("system timeout")
+        );
+    }
+
+    #[test]
+    fn test_parse_cancel_results() {
+        let json = r#"{"code":"200000","data":{"orderId":"671124f9365ccb00073debd4"}}"#;
+        let res: KuCoinResponse<CanceledOrder> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            res.into_data().unwrap().order_id,
+            "671124f9365ccb00073debd4"
+        );
+
+        let json = r#"{"code":"200000","data":{"clientOid":"5c52e11203aa677f33e493fb"}}"#;
+        let res: KuCoinResponse<CanceledClientOrder> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            res.into_data().unwrap().client_oid,
+            "5c52e11203aa677f33e493fb"
+        );
+
+        let json = r#"{"code":"200000","data":"success"}"#;
+        let res: KuCoinResponse<String> = serde_json::from_str(json).unwrap();
+        assert_eq!(res.into_data().unwrap(), "success");
+
+        let json = r#"{"code":"200000","data":{"succeedSymbols":["BTC-USDT","ETH-USDT"],"failedSymbols":[{"symbol":"BTC-USDC","error":"can't cancel, system timeout"}]}}"#;
+        let res: KuCoinResponse<CancelAllRes> = serde_json::from_str(json).unwrap();
+        let report = res.into_data().unwrap();
+        assert_eq!(report.succeed_symbols, vec!["BTC-USDT", "ETH-USDT"]);
+        assert_eq!(report.failed_symbols[0].symbol.as_deref(), Some("BTC-USDC"));
+    }
+
     #[tokio::test]
     async fn test_cancel_partial_order() {
         // 1. Setup Credentials
@@ -1,10 +1,13 @@
 use crate::{
     client::rest::KuCoinClient,
     types::{
+        KuCoinResponse, dry_run_id,
         transfer::{AccountType, TransferData, TransferRequest, TransferType},
-        KuCoinResponse,
     },
-    utils::errors::{KucoinErrors, KucoinResults},
+    utils::{
+        errors::{KucoinErrors, KucoinResults},
+        num::ToAmount,
+    },
 };
 use uuid::Uuid;
 
@@ -23,13 +26,13 @@ impl TransferRequest {
     /// * `transfer_type` - The nature of the transfer (e.g., `Internal`).
     pub fn new(
         currency: &str,
-        amount: f64,
+        amount: impl ToAmount,
         src_type: AccountType,
         dest_type: AccountType,
         transfer_type: TransferType,
     ) -> Self {
         TransferRequest {
-            amount: amount.to_string(),
+            amount: amount.to_amount(),
             client_oid: Uuid::new_v4().to_string(),
             currency: currency.to_string(),
             from_account_tag: None,
@@ -114,7 +117,11 @@ impl<'a> TransferHandler<'a> {
 
         let res = self
             .client
-            .send::<KuCoinResponse<TransferData>>("POST", &body, endpoint)
+            .send_mutation("POST", &body, endpoint, || {
+                KuCoinResponse::synthetic(TransferData {
+                    order_id: dry_run_id(),
+                })
+            })
             .await?;
         Ok(res)
     }
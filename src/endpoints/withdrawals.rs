@@ -1,10 +1,10 @@
 use crate::{
     client::rest::KuCoinClient,
     types::{
-        KuCoinResponse,
-        withdraw::{WithdrawRequest, WithdrawResponse, WithdrawType},
+        KuCoinResponse, dry_run_id,
+        withdraw::{FeeDeductType, WithdrawRequest, WithdrawResponse, WithdrawType},
     },
-    utils::errors::KucoinResults,
+    utils::{errors::KucoinResults, num::ToAmount},
 };
 
 pub struct WithdrawHandler<'a> {
@@ -13,9 +13,14 @@ pub struct WithdrawHandler<'a> {
 
 impl WithdrawRequest {
     /// Creates a new withdrawal request.
-    pub fn new(currency: &str, to_address: &str, amount: f64, withdraw_type: WithdrawType) -> Self {
+    pub fn new(
+        currency: &str,
+        to_address: &str,
+        amount: impl ToAmount,
+        withdraw_type: WithdrawType,
+    ) -> Self {
         WithdrawRequest {
-            amount: amount.to_string(),
+            amount: amount.to_amount(),
             chain: None,
             currency: currency.to_string(),
             fee_deduct_type: None,
@@ -27,7 +32,8 @@ impl WithdrawRequest {
         }
     }
 
-    /// Sets the chain name (e.g., "ERC20").
+    /// Sets the chain id (e.g., "eth", "trx").
+    /// Supported chains can be listed with `MarketHandler::enabled_chains`.
     pub fn set_chain(mut self, chain: &str) -> Self {
         self.chain = Some(chain.to_string());
         self
@@ -60,8 +66,8 @@ impl WithdrawRequest {
     /// the system will initially deduct the transaction fees from your main account.
     /// But if the balance in your main account is not sufficient to support the withdrawal,
     /// the system will deduct the fees from your withdrawal amount.
-    pub fn set_fee_deduct_type(mut self, type_: &str) -> Self {
-        self.fee_deduct_type = Some(type_.to_string());
+    pub fn set_fee_deduct_type(mut self, fee_deduct_type: FeeDeductType) -> Self {
+        self.fee_deduct_type = Some(fee_deduct_type);
         self
     }
 }
@@ -77,7 +83,11 @@ impl<'a> WithdrawHandler<'a> {
 
         let res = self
             .client
-            .send::<KuCoinResponse<WithdrawResponse>>("POST", &payload, endpoint)
+            .send_mutation("POST", &payload, endpoint, || {
+                KuCoinResponse::synthetic(WithdrawResponse {
+                    withdrawal_id: dry_run_id(),
+                })
+            })
             .await?;
 
         Ok(res)
@@ -2,3 +2,11 @@ pub mod client;
 pub mod endpoints;
 pub mod types;
 mod utils;
+
+pub use utils::{
+    errors::{KucoinErrors, KucoinResults},
+    num::{Amount, ToAmount, floor_to_increment, round_to_increment},
+};
+
+/// Exact decimal type behind `Amount`, re-exported so callers need not depend on `rust_decimal`.
+pub use rust_decimal::Decimal;
@@ -0,0 +1,392 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::transfer::AccountType,
+    utils::{enums::string_enum, num::Amount},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    /// Account ID
+    pub id: String,
+    /// Currency
+    pub currency: String,
+    /// Account type: main, trade or margin
+    #[serde(rename = "type")]
+    pub account_type: AccountType,
+    /// Total funds in the account
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub balance: Amount,
+    /// Funds available to withdraw or trade
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub available: Amount,
+    /// Funds on hold (not available for use)
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub holds: Amount,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountDetail {
+    /// The currency of the account
+    pub currency: String,
+    /// Total funds in the account
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub balance: Amount,
+    /// Funds available to withdraw or trade
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub available: Amount,
+    /// Funds on hold (not available for use)
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub holds: Amount,
+}
+
+/// Query for the classic account ledgers (`/api/v1/accounts/ledgers`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerRequest {
+    /// Currency, up to 10 comma-separated currencies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Direction: in, out
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<Direction>,
+    /// Business type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub biz_type: Option<BizType>,
+    /// Start time (milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<i64>,
+    /// End time (milliseconds), the time range cannot exceed 24 hours
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<i64>,
+    /// Current request page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_page: Option<i64>,
+    /// Number of results per request. Minimum is 10, maximum is 500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+}
+
+/// Query for the high-frequency trade account ledgers (`/api/v1/hf/accounts/ledgers`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HfLedgerRequest {
+    /// Currency, up to 10 comma-separated currencies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Direction: in, out
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<Direction>,
+    /// Business type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub biz_type: Option<BizType>,
+    /// The id of the last record of the previous page, used as the cursor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<String>,
+    /// Default 100, maximum 200
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Start time (milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<i64>,
+    /// End time (milliseconds), the time range cannot exceed 7 days
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<i64>,
+}
+
+/// Direction of a balance change: in, out
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    In,
+    Out,
+}
+
+string_enum! {
+    /// Business type of a ledger entry.
+    ///
+    /// The classic ledgers accept DEPOSIT, WITHDRAW, TRANSFER, SUB_TRANSFER, TRADE_EXCHANGE,
+    /// MARGIN_EXCHANGE, KUCOIN_BONUS and BROKER_TRANSFER; the HF ledgers accept TRADE_EXCHANGE,
+    /// TRANSFER, SUB_TRANSFER, RETURNED_FEES, DEDUCTION_FEES and OTHER.
+    pub enum BizType {
+        Deposit => "DEPOSIT",
+        Withdraw => "WITHDRAW",
+        Transfer => "TRANSFER",
+        SubTransfer => "SUB_TRANSFER",
+        TradeExchange => "TRADE_EXCHANGE",
+        MarginExchange => "MARGIN_EXCHANGE",
+        KucoinBonus => "KUCOIN_BONUS",
+        BrokerTransfer => "BROKER_TRANSFER",
+        ReturnedFees => "RETURNED_FEES",
+        DeductionFees => "DEDUCTION_FEES",
+        Other => "OTHER",
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerList {
+    /// Current request page
+    pub current_page: i64,
+    /// Number of results per request
+    pub page_size: i64,
+    /// Total number of records
+    pub total_num: i64,
+    /// Total number of pages
+    pub total_page: i64,
+    pub items: Vec<Ledger>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ledger {
+    /// Unique ID
+    pub id: String,
+    /// Currency
+    pub currency: String,
+    /// The total amount of assets (fees included) involved in assets changes such as
+    /// transaction, withdrawal and bonus distribution.
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub amount: Amount,
+    /// Fees generated in transaction, withdrawal, etc.
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub fee: Amount,
+    /// Remaining funds after the transaction.
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub balance: Amount,
+    /// The account type of the master user: MAIN, TRADE, MARGIN or CONTRACT.
+    pub account_type: String,
+    /// Business type leading to changes in funds, such as exchange, withdrawal, deposit,
+    /// KUCOIN_BONUS, REFERRAL_BONUS, Lendings, etc.
+    pub biz_type: BizType,
+    /// Side, out or in
+    pub direction: Direction,
+    /// Time of event
+    pub created_at: i64,
+    /// Business related information such as order ID, serial no., etc.
+    pub context: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HfLedger {
+    /// Unique ID, also the cursor for the next page (`lastId`)
+    pub id: String,
+    /// currency
+    pub currency: String,
+    /// Change in funds balance
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub amount: Amount,
+    /// Transaction, Deposit or withdrawal fee
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub fee: Amount,
+    /// Users in some regions need query this field
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub tax: Option<Amount>,
+    /// Total balance of funds after change
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub balance: Amount,
+    /// Master account type TRADE_HF
+    pub account_type: String,
+    /// Trnasaction type, such as TRANSFER, TRADE_EXCHANGE, etc.
+    pub biz_type: BizType,
+    /// Direction of transaction (out or in)
+    pub direction: Direction,
+    /// Created time
+    #[serde(deserialize_with = "crate::utils::num::int_lenient")]
+    pub created_at: i64,
+    /// Core transaction parameter
+    pub context: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSummary {
+    /// User VIP level
+    pub level: i64,
+    /// Number of sub-accounts
+    pub sub_quantity: i64,
+    /// Number of sub-accounts with spot trading permissions enabled
+    pub spot_sub_quantity: i64,
+    /// Number of sub-accounts with margin trading permissions enabled
+    pub margin_sub_quantity: i64,
+    /// Number of sub-accounts with futures trading permissions enabled
+    pub futures_sub_quantity: i64,
+    /// Number of sub-accounts with option trading permissions enabled
+    pub option_sub_quantity: Option<i64>,
+    /// Max number of sub-accounts = maxDefaultSubQuantity + maxSpotSubQuantity
+    pub max_sub_quantity: i64,
+    /// Max number of default open sub-accounts (according to VIP level)
+    pub max_default_sub_quantity: i64,
+    /// Max number of sub-accounts with additional spot trading permissions
+    pub max_spot_sub_quantity: i64,
+    /// Max number of sub-accounts with additional margin trading permissions
+    pub max_margin_sub_quantity: i64,
+    /// Max number of sub-accounts with additional futures trading permissions
+    pub max_futures_sub_quantity: i64,
+    /// Max number of sub-accounts with additional option trading permissions
+    pub max_option_sub_quantity: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyInfo {
+    /// Remarks
+    pub remark: String,
+    /// API Key
+    pub api_key: String,
+    /// API Version
+    pub api_version: i64,
+    /// [Permissions](/docs-new/introduction), comma separated, e.g. "General,Spot"
+    pub permission: String,
+    /// IP whitelist, comma separated
+    pub ip_whitelist: Option<String>,
+    /// Apikey create time
+    pub created_at: i64,
+    /// Account UID
+    pub uid: i64,
+    /// Whether it is the master account.
+    pub is_master: bool,
+    /// Sub Name, only present for sub-account keys
+    pub sub_name: Option<String>,
+}
+
+impl ApiKeyInfo {
+    /// The permissions granted to the key.
+    pub fn permissions(&self) -> Vec<Permission> {
+        Permission::parse_list(&self.permission)
+    }
+
+    /// Whether the key has been granted `permission`.
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions().contains(&permission)
+    }
+}
+
+string_enum! {
+    /// API key permission.
+    pub enum Permission {
+        General => "General",
+        Spot => "Spot",
+        Margin => "Margin",
+        Futures => "Futures",
+        Unified => "Unified",
+        /// Flex transfers between accounts and sub-accounts.
+        InnerTransfer => "InnerTransfer",
+        /// Withdrawals.
+        Transfer => "Transfer",
+        Earn => "Earn",
+    }
+}
+
+impl Permission {
+    /// Parse a comma separated permission list; names this crate does not know are kept as
+    /// `Permission::Unknown`.
+    pub fn parse_list(list: &str) -> Vec<Permission> {
+        list.split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .filter_map(|name| name.parse().ok())
+            .collect()
+    }
+
+    /// Join permissions into the comma separated list used by the API.
+    pub fn join_list(permissions: &[Permission]) -> String {
+        permissions
+            .iter()
+            .map(Permission::as_str)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// The handlers of `KuCoinClient`, used to check that an API key can serve them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    Account,
+    Deposit,
+    Market,
+    Spot,
+    SubAccount,
+    Transfer,
+    Withdraw,
+}
+
+impl Scope {
+    /// The permission an API key needs for the handler.
+    pub fn required_permission(&self) -> Permission {
+        match self {
+            Scope::Account | Scope::Deposit | Scope::Market | Scope::SubAccount => {
+                Permission::General
+            }
+            Scope::Spot => Permission::Spot,
+            Scope::Transfer => Permission::InnerTransfer,
+            Scope::Withdraw => Permission::Transfer,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaseFee {
+    /// Base taker fee rate
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub taker_fee_rate: Amount,
+    /// Base maker fee rate
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub maker_fee_rate: Amount,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFee {
+    /// The unique identity of the trading pair
+    pub symbol: String,
+    /// Actual taker fee rate of the symbol
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub taker_fee_rate: Amount,
+    /// Actual maker fee rate of the symbol
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub maker_fee_rate: Amount,
+}
+
+/// Currency type of the base fee: crypto or fiat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurrencyType {
+    Crypto,
+    Fiat,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAccount {
+    /// Account equity = marginBalance + unrealisedPNL
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub account_equity: Amount,
+    /// Unrealized profit and loss
+    #[serde(
+        rename = "unrealisedPNL",
+        deserialize_with = "crate::utils::num::amount"
+    )]
+    pub unrealised_pnl: Amount,
+    /// Margin balance = positionMargin + orderMargin + frozenFunds + availableBalance -
+    /// unrealisedPNL
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub margin_balance: Amount,
+    /// Position margin
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub position_margin: Amount,
+    /// Order margin
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub order_margin: Amount,
+    /// Frozen funds for out-transfer
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub frozen_funds: Amount,
+    /// Available balance
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub available_balance: Amount,
+    /// Currency, XBT stands for BTC
+    pub currency: String,
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::utils::{enums::string_enum, num::Amount};
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DepositHistoryRequest {
@@ -39,7 +41,8 @@ pub struct Deposit {
     /// Deposit address
     pub address: Option<String>,
     /// Deposit amount
-    pub amount: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub amount: Option<Amount>,
     /// Whether there is any debt.A quick rollback will cause the deposit to fail. If the deposit
     /// fails, you will need to repay the balance.
     pub arrears: Option<bool>,
@@ -50,7 +53,8 @@ pub struct Deposit {
     /// Currency
     pub currency: Option<String>,
     /// Fees charged for deposit
-    pub fee: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub fee: Option<Amount>,
     /// Internal deposit or not
     pub is_inner: Option<bool>,
     /// Address remark. If there’s no remark, it is empty.
@@ -65,15 +69,13 @@ pub struct Deposit {
     pub wallet_tx_id: Option<String>,
 }
 
-/// Status. Available value: PROCESSING, SUCCESS, and FAILURE
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum DepositStatus {
-    Failure,
-    Processing,
-    Success,
-    #[serde(rename = "TRM_MGT_REJECTED")]
-    TrmMgtRejected,
-    #[serde(rename = "WAIT_TRM_MGT")]
-    WaitTrmMgt,
+string_enum! {
+    /// Status. Available value: PROCESSING, SUCCESS, and FAILURE
+    pub enum DepositStatus {
+        Failure => "FAILURE",
+        Processing => "PROCESSING",
+        Success => "SUCCESS",
+        TrmMgtRejected => "TRM_MGT_REJECTED",
+        WaitTrmMgt => "WAIT_TRM_MGT",
+    }
 }
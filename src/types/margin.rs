@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{
+    enums::string_enum,
+    num::{self, Amount, ToAmount},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossMarginAccount {
+    /// Total Assets in Quote Currency
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub total_asset_of_quote_currency: Amount,
+    /// Total Liability in Quote Currency
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub total_liability_of_quote_currency: Amount,
+    /// debt ratio
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub debt_ratio: Amount,
+    /// Position status
+    pub status: MarginStatus,
+    /// Margin account list
+    pub accounts: Vec<MarginAsset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAccount {
+    /// Total Assets in Quote Currency
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub total_asset_of_quote_currency: Amount,
+    /// Total Liability in Quote Currency
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub total_liability_of_quote_currency: Amount,
+    /// timestamp
+    pub timestamp: i64,
+    pub assets: Vec<IsolatedMarginPair>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginPair {
+    /// Symbol
+    pub symbol: String,
+    /// Position status
+    pub status: MarginStatus,
+    /// debt ratio
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub debt_ratio: Amount,
+    /// Base currency of the pair
+    pub base_asset: MarginAsset,
+    /// Quote currency of the pair
+    pub quote_asset: MarginAsset,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAsset {
+    /// currency
+    pub currency: String,
+    /// Total Assets
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub total: Amount,
+    /// Account available assets (total assets - frozen)
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub available: Amount,
+    /// Account frozen assets
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub hold: Amount,
+    /// Liabilities (borrowed principal plus accrued interest)
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub liability: Amount,
+    /// Borrowed principal
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub liability_principal: Option<Amount>,
+    /// Accrued interest
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub liability_interest: Option<Amount>,
+    /// The user's remaining maximum loan amount
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub max_borrow_size: Amount,
+    /// Support borrow or not
+    pub borrow_enabled: bool,
+    /// Support transfer or not
+    pub transfer_in_enabled: bool,
+}
+
+string_enum! {
+    /// Position status of a margin account.
+    pub enum MarginStatus {
+        /// Existing loans
+        Effective => "EFFECTIVE",
+        /// Bankruptcy
+        Bankruptcy => "BANKRUPTCY",
+        /// Closing
+        Liquidation => "LIQUIDATION",
+        /// Repayment
+        Repay => "REPAY",
+        /// Borrowing
+        Borrow => "BORROW",
+    }
+}
+
+impl CrossMarginAccount {
+    /// Assets that currently carry a liability.
+    pub fn borrowed(&self) -> Vec<&MarginAsset> {
+        self.accounts
+            .iter()
+            .filter(|asset| asset.liability > Amount::ZERO)
+            .collect()
+    }
+}
+
+impl IsolatedMarginAccount {
+    /// Pairs whose debt ratio is at or above `threshold` (e.g. 0.8).
+    /// An unparseable threshold matches no pair.
+    pub fn at_risk(&self, threshold: impl ToAmount) -> Vec<&IsolatedMarginPair> {
+        let Some(threshold) = num::parse_amount(&threshold.to_amount()) else {
+            return Vec::new();
+        };
+        self.assets
+            .iter()
+            .filter(|pair| pair.debt_ratio >= threshold)
+            .collect()
+    }
+}
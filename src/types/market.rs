@@ -0,0 +1,340 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{
+    enums::string_enum,
+    num::{self, Amount},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Currency {
+    /// A unique currency code that will never change
+    pub currency: String,
+    /// Currency name; will change after renaming
+    pub name: Option<String>,
+    /// Full currency name; will change after renaming
+    pub full_name: Option<String>,
+    /// Currency precision
+    pub precision: Option<i64>,
+    /// Number of block confirmations
+    pub confirms: Option<i64>,
+    /// Contract address
+    pub contract_address: Option<String>,
+    /// Margin support or not
+    pub is_margin_enabled: Option<bool>,
+    /// Debit support or not
+    pub is_debit_enabled: Option<bool>,
+    /// Chain list, null when the currency has no chain (e.g. fiat).
+    pub chains: Option<Vec<ChainInfo>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainInfo {
+    /// Chain name of currency
+    pub chain_name: String,
+    /// Chain id of currency, this is the value expected by `WithdrawRequest::set_chain`.
+    pub chain_id: String,
+    /// Minimum withdrawal amount
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub withdrawal_min_size: Option<Amount>,
+    /// Minimum deposit amount
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub deposit_min_size: Option<Amount>,
+    /// Withdraw fee rate
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub withdraw_fee_rate: Option<Amount>,
+    /// Minimum fees charged for withdrawal
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub withdrawal_min_fee: Option<Amount>,
+    /// Maximum fees charged for withdrawal
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub withdraw_max_fee: Option<Amount>,
+    /// Deposit fee rate (some currencies have this param, the default is empty)
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub deposit_fee_rate: Option<Amount>,
+    /// Withdraw support or not
+    pub is_withdraw_enabled: bool,
+    /// Deposit support or not
+    pub is_deposit_enabled: bool,
+    /// Number of block confirmations
+    pub confirms: Option<i64>,
+    /// The number of blocks (confirmations) for advance on-chain verification
+    pub pre_confirms: Option<i64>,
+    /// Contract address
+    pub contract_address: Option<String>,
+    /// Withdrawal precision bit, indicating the maximum supported length after the decimal
+    /// point of the withdrawal amount
+    pub withdraw_precision: Option<i64>,
+    /// Maximum amount of single withdrawal
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub max_withdraw: Option<Amount>,
+    /// Maximum amount of single deposit (only applicable to Lightning Network)
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub max_deposit: Option<Amount>,
+    /// Need for memo/tag or not
+    pub need_tag: Option<bool>,
+}
+
+impl Currency {
+    /// Chains on which both deposits and withdrawals are currently enabled.
+    pub fn enabled_chains(&self) -> Vec<&ChainInfo> {
+        self.chains
+            .iter()
+            .flatten()
+            .filter(|chain| chain.is_deposit_enabled && chain.is_withdraw_enabled)
+            .collect()
+    }
+
+    /// Look up a chain by its `chainId` or `chainName` (case-insensitive).
+    pub fn chain(&self, chain: &str) -> Option<&ChainInfo> {
+        self.chains.iter().flatten().find(|info| {
+            info.chain_id.eq_ignore_ascii_case(chain) || info.chain_name.eq_ignore_ascii_case(chain)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceStatusData {
+    /// Status of service: open, close or cancelonly
+    pub status: ServiceStatus,
+    /// Remark for operation
+    pub msg: Option<String>,
+}
+
+string_enum! {
+    /// Status of service: open, close or cancelonly
+    pub enum ServiceStatus {
+        /// Normal trading.
+        Open => "open",
+        /// Under maintenance, no orders can be placed or cancelled.
+        Close => "close",
+        /// Only order cancellations are accepted.
+        CancelOnly => "cancelonly",
+    }
+}
+
+impl ServiceStatus {
+    /// Whether new orders are accepted.
+    pub fn can_place_orders(&self) -> bool {
+        matches!(self, ServiceStatus::Open)
+    }
+
+    /// Whether order cancellations are accepted.
+    pub fn can_cancel_orders(&self) -> bool {
+        matches!(self, ServiceStatus::Open | ServiceStatus::CancelOnly)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolInfo {
+    /// Unique code of a symbol, it will not change after renaming
+    pub symbol: String,
+    /// Name of trading pairs, it will change after renaming
+    pub name: String,
+    /// Base currency, e.g. BTC.
+    pub base_currency: String,
+    /// Quote currency, e.g. USDT.
+    pub quote_currency: String,
+    /// The currency of charged fees.
+    pub fee_currency: String,
+    /// The trading market.
+    pub market: String,
+    /// The minimum order quantity required to place an order.
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub base_min_size: Amount,
+    /// The minimum order funds required to place a market order.
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub quote_min_size: Amount,
+    /// The maximum order size required to place an order.
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub base_max_size: Amount,
+    /// The maximum order funds required to place a market order.
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub quote_max_size: Amount,
+    /// Quantity increment: The quantity for an order must be a positive integer multiple of
+    /// this increment.
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub base_increment: Amount,
+    /// Quote increment: The funds for a market order must be a positive integer multiple of
+    /// this increment.
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub quote_increment: Amount,
+    /// Price increment: The price of an order must be a positive integer multiple of this
+    /// increment.
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub price_increment: Amount,
+    /// Threshold for price protection
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub price_limit_rate: Option<Amount>,
+    /// The minimum trading amounts
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub min_funds: Option<Amount>,
+    /// Available for margin or not.
+    pub is_margin_enabled: bool,
+    /// Available for transaction or not.
+    pub enable_trading: bool,
+    /// Fee Type
+    pub fee_category: Option<i64>,
+    /// The maker fee coefficient. The actual fee needs to be multiplied by this coefficient.
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub maker_fee_coefficient: Option<Amount>,
+    /// The taker fee coefficient. The actual fee needs to be multiplied by this coefficient.
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub taker_fee_coefficient: Option<Amount>,
+    /// Whether it is a Special Treatment symbol
+    pub st: Option<bool>,
+    /// The time trading starts, in milliseconds (only for new listings).
+    pub trading_start_time: Option<i64>,
+}
+
+impl SymbolInfo {
+    /// Whether the increments or the `enableTrading` flag differ from `other`.
+    pub fn rules_changed(&self, other: &SymbolInfo) -> bool {
+        self.base_increment != other.base_increment
+            || self.quote_increment != other.quote_increment
+            || self.price_increment != other.price_increment
+            || self.enable_trading != other.enable_trading
+    }
+
+    /// Round a price to the nearest multiple of `priceIncrement`.
+    pub fn round_price(&self, price: Amount) -> Amount {
+        num::round_to_increment(price, self.price_increment)
+    }
+
+    /// Round a base-currency size down to a multiple of `baseIncrement`.
+    pub fn round_size(&self, size: Amount) -> Amount {
+        num::floor_to_increment(size, self.base_increment)
+    }
+
+    /// Round quote-currency funds down to a multiple of `quoteIncrement`.
+    pub fn round_funds(&self, funds: Amount) -> Amount {
+        num::floor_to_increment(funds, self.quote_increment)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnouncementRequest {
+    /// Announcement type, default is `latest-announcements`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ann_type: Option<AnnouncementType>,
+    /// Language type, the default is `en_US`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+    /// Announcement online start time (milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// Announcement online end time (milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// page number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_page: Option<i64>,
+    /// page Size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+}
+
+string_enum! {
+    /// Announcement type
+    pub enum AnnouncementType {
+        LatestAnnouncements => "latest-announcements",
+        Activities => "activities",
+        NewListings => "new-listings",
+        ProductUpdates => "product-updates",
+        Vip => "vip",
+        MaintenanceUpdates => "maintenance-updates",
+        Delistings => "delistings",
+        Others => "others",
+        ApiCampaigns => "api-campaigns",
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnouncementList {
+    /// Total Number
+    pub total_num: i64,
+    pub items: Vec<Announcement>,
+    /// Current page
+    pub current_page: i64,
+    /// Page size
+    pub page_size: i64,
+    /// Total Page
+    pub total_page: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Announcement {
+    /// Announcement ID
+    pub ann_id: i64,
+    /// Announcement title
+    pub ann_title: String,
+    /// Announcement types; types this crate does not know are kept as `Unknown`.
+    pub ann_type: Vec<AnnouncementType>,
+    /// Announcement description
+    pub ann_desc: Option<String>,
+    /// Announcement release time, Unix millisecond timestamp format
+    pub c_time: i64,
+    /// language type
+    pub language: Option<String>,
+    /// Announcement link
+    pub ann_url: Option<String>,
+}
+
+impl Announcement {
+    /// Whether the announcement is tagged with `ann_type`.
+    pub fn has_type(&self, ann_type: AnnouncementType) -> bool {
+        self.ann_type.contains(&ann_type)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllTickers {
+    /// timestamp
+    pub time: i64,
+    pub ticker: Vec<Ticker>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker {
+    /// Symbol
+    pub symbol: String,
+    /// Name of trading pairs, it will change after renaming
+    pub symbol_name: Option<String>,
+    /// Best bid price
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub buy: Option<Amount>,
+    /// Best ask price
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub sell: Option<Amount>,
+    /// 24h change rate
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub change_rate: Option<Amount>,
+    /// 24h change price
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub change_price: Option<Amount>,
+    /// Highest price in 24h
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub high: Option<Amount>,
+    /// Lowest price in 24h
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub low: Option<Amount>,
+    /// 24h volume, executed based on base currency
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub vol: Option<Amount>,
+    /// 24h traded amount
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub vol_value: Option<Amount>,
+    /// Last traded price
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub last: Option<Amount>,
+    /// Average trading price in the last 24 hours
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub average_price: Option<Amount>,
+}
@@ -1,13 +1,54 @@
+pub mod account;
 pub mod deposit;
+pub mod margin;
+pub mod market;
+pub mod oco;
+pub mod portfolio;
 pub mod spot;
 pub mod sup_account;
 pub mod transfer;
 pub mod withdraw;
 
 use serde::{Deserialize, Serialize};
+
+use crate::utils::errors::{KucoinErrors, KucoinResults};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KuCoinResponse<T> {
     pub code: String,
     pub msg: Option<String>, // Error message
     pub data: Option<T>,
 }
+
+/// Success code returned by the API.
+pub const SUCCESS_CODE: &str = "200000";
+
+/// Prefix of the ids made up by dry-run mode.
+pub const DRY_RUN_PREFIX: &str = "dry-run-";
+
+impl<T> KuCoinResponse<T> {
+    /// A successful response carrying `data`, as produced by dry-run mode.
+    pub fn synthetic(data: T) -> Self {
+        KuCoinResponse {
+            code: SUCCESS_CODE.to_string(),
+            msg: None,
+            data: Some(data),
+        }
+    }
+
+    /// Unwrap the payload, turning a missing `data` into `KucoinErrors::ApiError`.
+    pub fn into_data(self) -> KucoinResults<T> {
+        match self.data {
+            Some(data) => Ok(data),
+            None => Err(KucoinErrors::ApiError {
+                code: self.code,
+                msg: self.msg.unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+/// A unique id for a synthetic (dry-run) result.
+pub(crate) fn dry_run_id() -> String {
+    format!("{}{}", DRY_RUN_PREFIX, uuid::Uuid::new_v4().simple())
+}
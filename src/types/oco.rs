@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+
+use super::spot::Side;
+use crate::utils::{enums::string_enum, num::Amount};
+
+/// One-cancels-the-other order: a limit order at `price` paired with a stop-limit order
+/// (`stop_price` trigger, `limit_price` limit). When one side executes the other is canceled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoOrderRequest {
+    /// Symbol
+    pub symbol: String,
+    /// specify if the order is to 'buy' or 'sell'
+    pub side: Side,
+    /// Price of the limit (take-profit) order
+    pub price: String,
+    /// Quantity in base currency
+    pub size: String,
+    /// Trigger price of the stop-limit order
+    pub stop_price: String,
+    /// Limit price of the stop-limit order once triggered
+    pub limit_price: String,
+    /// Only TRADE (spot) is supported
+    pub trade_type: String,
+    /// Client Order Id, unique per order, at most 40 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_oid: Option<String>,
+    /// Order placement remarks, length cannot exceed 20 characters (ASCII)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoOrderData {
+    /// The unique order id generated by the trading system
+    pub order_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanceledOcoOrders {
+    /// Ids of the canceled limit and stop-limit orders
+    pub cancelled_order_ids: Vec<String>,
+}
+
+/// Query for OCO orders (`/api/v3/oco/orders`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoListRequest {
+    /// Symbol
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// Comma-separated order ids
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_ids: Option<String>,
+    /// Start time (milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<i64>,
+    /// End time (milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<i64>,
+    /// Current page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_page: Option<i64>,
+    /// Number of results per page, 10 to 500, default 50
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoOrderList {
+    /// Current request page
+    pub current_page: i64,
+    /// Number of results per request
+    pub page_size: i64,
+    /// Total number of records
+    pub total_num: i64,
+    /// Total number of pages
+    pub total_page: i64,
+    pub items: Vec<OcoOrder>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoOrder {
+    /// Order id
+    pub order_id: String,
+    /// Symbol
+    pub symbol: String,
+    /// The user self-defined order id
+    #[serde(default)]
+    pub client_oid: Option<String>,
+    /// Time the order was placed (milliseconds)
+    pub order_time: i64,
+    /// Order status
+    pub status: OcoStatus,
+}
+
+/// An OCO order with the state of its two legs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoOrderDetail {
+    /// Order id
+    pub order_id: String,
+    /// Symbol
+    pub symbol: String,
+    /// The user self-defined order id
+    #[serde(default)]
+    pub client_oid: Option<String>,
+    /// Time the order was placed (milliseconds)
+    pub order_time: i64,
+    /// Order status
+    pub status: OcoStatus,
+    /// The limit and stop-limit legs
+    pub orders: Vec<OcoLeg>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoLeg {
+    /// Leg order id
+    pub id: String,
+    /// Symbol
+    pub symbol: String,
+    /// Side: buy, sell
+    pub side: Side,
+    /// Limit price
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub price: Amount,
+    /// Trigger price, "0" for the limit leg
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub stop_price: Amount,
+    /// Quantity
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub size: Amount,
+    /// Leg status
+    pub status: OcoStatus,
+}
+
+string_enum! {
+    /// Status of an OCO order or of one of its legs.
+    pub enum OcoStatus {
+        New => "NEW",
+        Done => "DONE",
+        Triggered => "TRIGGERED",
+        Cancelled => "CANCELLED",
+    }
+}
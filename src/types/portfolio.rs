@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::num::Amount;
+
+/// Selects the account types merged into a portfolio snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioRequest {
+    /// Currency the holdings are valued in (e.g. "USDT").
+    pub quote: String,
+    /// Include the funding (main) and spot (trade) accounts.
+    pub spot: bool,
+    /// Include the cross margin account.
+    pub margin: bool,
+    /// Include the isolated margin accounts.
+    pub isolated: bool,
+    /// Futures settlement currencies to include (e.g. "USDT", "XBT"), empty to skip futures.
+    pub futures: Vec<String>,
+    /// Include the balances of every sub-account.
+    pub sub_accounts: bool,
+}
+
+/// Where a balance is held.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HoldingSource {
+    Main,
+    Trade,
+    Margin,
+    Isolated,
+    Futures,
+    /// A sub-account, by sub-account name.
+    SubAccount(String),
+}
+
+/// Every balance of a currency across the selected accounts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Holding {
+    /// Currency
+    pub currency: String,
+    /// Total assets, all sources summed.
+    pub total: Amount,
+    /// Borrowed amount (principal and interest) owed on margin accounts.
+    pub liability: Amount,
+    /// Assets per source.
+    pub sources: HashMap<HoldingSource, Amount>,
+    /// Price of one unit in the quote currency, `None` if no market could price it.
+    pub price: Option<Amount>,
+    /// Net value (`total - liability`) in the quote currency, `None` if unpriceable.
+    pub value: Option<Amount>,
+}
+
+impl Holding {
+    /// Net amount once liabilities are repaid.
+    pub fn net(&self) -> Amount {
+        self.total - self.liability
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Portfolio {
+    /// Currency the holdings are valued in.
+    pub quote: String,
+    /// Holdings merged by currency, sorted by currency.
+    pub holdings: Vec<Holding>,
+    /// Sum of the values of the priced holdings.
+    pub total_value: Amount,
+    /// Currencies held that could not be priced in the quote currency; they are part of
+    /// `holdings` with a `None` value and excluded from `total_value`.
+    pub unpriced: Vec<String>,
+    /// Sources that could not be read (e.g. futures without the Futures permission); their
+    /// balances are missing from `holdings` and `total_value`.
+    pub failed: Vec<FailedSource>,
+}
+
+/// A portfolio source whose balances could not be fetched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedSource {
+    /// The source: "spot", "margin", "isolated", "futures:<currency>", "sub-accounts" (the
+    /// list itself) or "sub-account:<name>"
+    pub source: String,
+    /// Error message
+    pub error: String,
+}
@@ -10,8 +10,12 @@
 //     let json = r#"{"answer": 42}"#;
 //     let model: SpotContract = serde_json::from_str(&json).unwrap();
 // }
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::utils::{enums::string_enum, num::Amount};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchSpotContract {
@@ -152,33 +156,94 @@ pub enum TradeType {
     Market,
 }
 
-/// [Self Trade Prevention](/docs-new/enums-definitions) is divided into four strategies: CN,
-/// CO, CB, and DC.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum Stp {
-    #[serde(rename = "CB")]
-    Cb,
-    #[serde(rename = "CN")]
-    Cn,
-    #[serde(rename = "CO")]
-    Co,
-    #[serde(rename = "DC")]
-    Dc,
+string_enum! {
+    /// [Self Trade Prevention](/docs-new/enums-definitions) is divided into four strategies: CN,
+    /// CO, CB, and DC.
+    pub enum Stp {
+        Cb => "CB",
+        Cn => "CN",
+        Co => "CO",
+        Dc => "DC",
+    }
 }
 
-/// [Time in Force](/docs-new/enums-definitions) is a special strategy used during trading to
-/// specify how long an order remains active before execution or expiration. **Market orders
-/// are not supported**. Order fills include self-fills. Default is `GTC`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum TimeInForce {
-    #[serde(rename = "FOK")]
-    Fok,
-    #[serde(rename = "GTC")]
-    Gtc,
-    #[serde(rename = "GTT")]
-    Gtt,
-    #[serde(rename = "IOC")]
-    Ioc,
+string_enum! {
+    /// [Time in Force](/docs-new/enums-definitions) is a special strategy used during trading to
+    /// specify how long an order remains active before execution or expiration. **Market orders
+    /// are not supported**. Order fills include self-fills. Default is `GTC`.
+    pub enum TimeInForce {
+        Fok => "FOK",
+        Gtc => "GTC",
+        Gtt => "GTT",
+        Ioc => "IOC",
+    }
+}
+
+string_enum! {
+    /// Account an order trades from.
+    pub enum OrderTradeType {
+        /// Spot
+        Trade => "TRADE",
+        /// Cross margin
+        MarginTrade => "MARGIN_TRADE",
+        /// Isolated margin
+        MarginIsolatedTrade => "MARGIN_ISOLATED_TRADE",
+    }
+}
+
+string_enum! {
+    /// Last operation on an order.
+    pub enum OpType {
+        Deal => "DEAL",
+        Cancel => "CANCEL",
+    }
+}
+
+string_enum! {
+    /// Where an order was placed from.
+    pub enum Channel {
+        Api => "API",
+        Web => "WEB",
+        Ios => "IOS",
+        Android => "ANDROID",
+    }
+}
+
+/// A trading rule an order breaks, as found by `SpotOrderRequest::validate`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderViolation {
+    /// The rules are those of another symbol.
+    SymbolMismatch { expected: String, got: String },
+    /// Trading is currently disabled on the symbol.
+    TradingDisabled,
+    /// Limit orders need a price.
+    MissingPrice,
+    /// Limit orders need a size.
+    MissingSize,
+    /// Market orders need exactly one of size or funds.
+    SizeOrFunds,
+    /// Iceberg orders need a visible size.
+    MissingVisibleSize,
+    /// The field is not a positive number.
+    InvalidNumber { field: &'static str, value: String },
+    /// The field is not a multiple of its increment.
+    NotIncrement {
+        field: &'static str,
+        value: Amount,
+        increment: Amount,
+    },
+    /// The field is below the symbol minimum.
+    BelowMin {
+        field: &'static str,
+        value: Amount,
+        min: Amount,
+    },
+    /// The field is above the symbol maximum.
+    AboveMax {
+        field: &'static str,
+        value: Amount,
+        max: Amount,
+    },
 }
 
 /// Cancel Partial Order for Spot request.
@@ -197,15 +262,218 @@ pub struct SpotCancelRequest {
 #[serde(rename_all = "camelCase")]
 pub struct SpotCanceledData {
     /// The size you canceled
-    pub cancel_size: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub cancel_size: Amount,
     /// order id
     pub order_id: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncOrderData {
+    /// The unique order id generated by the trading system
+    pub order_id: String,
+    /// The user self-defined order id.
+    pub client_oid: Option<String>,
+    /// Order creation time
+    pub order_time: i64,
+    /// Original order size
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub origin_size: Amount,
+    /// Deal size
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub deal_size: Amount,
+    /// Remain size
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub remain_size: Amount,
+    /// Cumulative canceled size
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub canceled_size: Amount,
+    /// Order Status. open: order is active; done: order has been completed
+    pub status: OrderStatus,
+    /// Matching time
+    pub match_time: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncOrderResult {
+    /// The unique order id generated by the trading system, absent on failure
+    pub order_id: Option<String>,
+    /// The user self-defined order id.
+    pub client_oid: Option<String>,
+    /// Order creation time
+    pub order_time: Option<i64>,
+    /// Original order size
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub origin_size: Option<Amount>,
+    /// Deal size
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub deal_size: Option<Amount>,
+    /// Remain size
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub remain_size: Option<Amount>,
+    /// Cumulative canceled size
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub canceled_size: Option<Amount>,
+    /// Order Status. open: order is active; done: order has been completed
+    pub status: Option<OrderStatus>,
+    /// Matching time
+    pub match_time: Option<i64>,
+    /// Add order success/failure
+    pub success: bool,
+    /// Error message
+    pub fail_msg: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncCancelData {
+    /// The unique order id, set when canceled by order id
+    pub order_id: Option<String>,
+    /// The user self-defined order id, set when canceled by client order id
+    pub client_oid: Option<String>,
+    /// Original order size
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub origin_size: Amount,
+    /// Deal size
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub deal_size: Amount,
+    /// Remain size
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub remain_size: Amount,
+    /// Cumulative canceled size
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub canceled_size: Amount,
+    /// Order Status. open: order is active; done: order has been completed
+    pub status: OrderStatus,
+}
+
+string_enum! {
+    /// Order Status. open: order is active; done: order has been completed
+    pub enum OrderStatus {
+        Open => "open",
+        Done => "done",
+    }
+}
+
+/// Modify (alter) order request for HF spot orders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotModifyRequest {
+    /// symbol
+    pub symbol: String,
+    /// The old client order id, either orderId or clientOid must be passed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_oid: Option<String>,
+    /// The old order id, either orderId or clientOid must be passed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    /// The modified price of the new order, at least one of price or size must be passed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_price: Option<String>,
+    /// The modified size of the new order, at least one of price or size must be passed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_size: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotModifiedData {
+    /// The new order id; modifying an order cancels it and places a new one.
+    pub new_order_id: String,
+    /// The original client order id
+    pub client_oid: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanceledOrder {
+    /// The unique order id of the canceled order
+    pub order_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanceledClientOrder {
+    /// Client Order Id of the canceled order
+    pub client_oid: String,
+}
+
+/// Raw answer to one batch request, results in request order.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BatchOrderResult {
-    code: String,
-    data: Vec<SpotOrderResult>,
+    pub code: String,
+    /// Error message when the whole batch is rejected
+    #[serde(default)]
+    pub msg: Option<String>,
+    #[serde(default)]
+    pub data: Vec<SpotOrderResult>,
+}
+
+/// One order of a batch placement, paired with its result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchOrderOutcome {
+    /// The order as it was submitted
+    pub request: SpotOrderRequest,
+    /// Success/failure and `fail_msg` of this order; `None` when its batch got no usable
+    /// answer, so the order may or may not have been placed (see `unknown_reason`)
+    pub result: Option<SpotOrderResult>,
+    /// Why the result is unknown: transport error, unreadable or mismatched response
+    pub unknown_reason: Option<String>,
+}
+
+impl BatchOrderOutcome {
+    /// Whether the exchange accepted the order.
+    pub fn is_placed(&self) -> bool {
+        self.result.as_ref().is_some_and(|result| result.success)
+    }
+
+    /// Whether the order was rejected, by the exchange or by order validation.
+    pub fn is_rejected(&self) -> bool {
+        self.result.as_ref().is_some_and(|result| !result.success)
+    }
+
+    /// Whether the fate of the order is unknown. Look it up with
+    /// `SpotHandler::order_by_client_oid` before sending it again.
+    pub fn is_unknown(&self) -> bool {
+        self.result.is_none()
+    }
+}
+
+/// Outcome of `place_multi_orders`, one entry per order in the order they were added.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchOrderReport {
+    pub outcomes: Vec<BatchOrderOutcome>,
+}
+
+impl BatchOrderReport {
+    pub fn len(&self) -> usize {
+        self.outcomes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.outcomes.is_empty()
+    }
+
+    /// Orders that were placed.
+    pub fn succeeded(&self) -> impl Iterator<Item = &BatchOrderOutcome> {
+        self.outcomes.iter().filter(|outcome| outcome.is_placed())
+    }
+
+    /// Orders that were rejected, alone or with their whole batch. These are safe to resend.
+    pub fn failed(&self) -> impl Iterator<Item = &BatchOrderOutcome> {
+        self.outcomes.iter().filter(|outcome| outcome.is_rejected())
+    }
+
+    /// Orders whose batch got no usable answer; they may have been placed.
+    pub fn unknown(&self) -> impl Iterator<Item = &BatchOrderOutcome> {
+        self.outcomes.iter().filter(|outcome| outcome.is_unknown())
+    }
+
+    pub fn all_succeeded(&self) -> bool {
+        self.outcomes.iter().all(BatchOrderOutcome::is_placed)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -242,23 +510,29 @@ pub struct SpotDatum {
     /// Whether there is a cancellation record for the order.
     pub cancel_exist: bool,
     /// Funds of canceled transactions
-    pub cancelled_funds: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub cancelled_funds: Amount,
     /// Number of canceled transactions
-    pub cancelled_size: String,
-    pub channel: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub cancelled_size: Amount,
+    pub channel: Channel,
     /// Client Order Id，unique identifier created by the user
     pub client_oid: String,
     pub created_at: i64,
     /// Funds of filled transactions
-    pub deal_funds: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub deal_funds: Amount,
     /// Number of filled transactions
-    pub deal_size: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub deal_size: Amount,
     /// [Handling fees](/docs-new/5327739m0)
-    pub fee: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub fee: Amount,
     /// currency used to calculate trading fee
     pub fee_currency: String,
     /// Order Funds
-    pub funds: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub funds: Amount,
     /// Whether its a hidden order.
     pub hidden: bool,
     /// Whether its a iceberg order.
@@ -268,21 +542,25 @@ pub struct SpotDatum {
     /// Whether to enter the orderbook: true: enter the orderbook; false: not enter the orderbook
     pub in_order_book: bool,
     pub last_updated_at: i64,
-    pub op_type: String,
+    pub op_type: OpType,
     /// Whether its a postOnly order.
     pub post_only: bool,
     /// Order price
-    pub price: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub price: Amount,
     /// Funds of remain transactions
-    pub remain_funds: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub remain_funds: Amount,
     /// Number of remain transactions
-    pub remain_size: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub remain_size: Amount,
     /// Order placement remarks
     pub remark: Option<String>,
     /// Order side
     pub side: Side,
     /// Order size
-    pub size: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub size: Amount,
     /// [Self Trade Prevention](/docs-new/enums-definitions) is divided into four strategies: CN,
     /// CO, CB, and DC.
     pub stp: Option<Stp>,
@@ -291,18 +569,434 @@ pub struct SpotDatum {
     /// Order tag
     pub tags: Option<String>,
     /// Users in some regions need query this field
-    pub tax: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub tax: Amount,
     /// [Time in Force](/docs-new/enums-definitions) is a special strategy used during trading to
     /// specify how long an order remains active before execution or expiration. **Market orders
     /// are not supported**. Order fills include self-fills. Default is `GTC`.
     pub time_in_force: TimeInForce,
     /// Trade type, redundancy param
-    pub trade_type: String,
+    pub trade_type: OrderTradeType,
     /// Order type
     #[serde(rename = "type")]
     pub datum_type: Type,
     /// Visible size of iceberg order in order book.
-    pub visible_size: String,
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub visible_size: Amount,
+}
+
+/// Query for the closed HF orders of a symbol (`/api/v1/hf/orders/done`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DoneOrdersRequest {
+    /// Symbol
+    pub symbol: String,
+    /// Side: buy, sell
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<Side>,
+    /// Order type: limit, market
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub order_type: Option<Type>,
+    /// The `lastId` of the previous page, used as the cursor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<i64>,
+    /// Default 20, maximum 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Start time (milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<i64>,
+    /// End time (milliseconds), the time range cannot exceed 7 days
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DoneOrderList {
+    /// Cursor for the next page, pass it back as `lastId`
+    #[serde(default)]
+    pub last_id: Option<i64>,
+    /// Closed orders, newest first
+    pub items: Vec<SpotDatum>,
+}
+
+/// Trigger direction of a stop order.
+///
+/// `loss` triggers when the last price falls to or below the stop price (sell stops),
+/// `entry` when it rises to or above it (buy stops).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StopType {
+    Loss,
+    Entry,
+}
+
+/// A stop (trigger) order: a limit or market order placed once the stop price is reached.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopOrderRequest {
+    /// Client Order Id, unique per order, at most 40 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_oid: Option<String>,
+    /// specify if the order is to 'buy' or 'sell'
+    pub side: Side,
+    /// symbol
+    pub symbol: String,
+    /// Order type placed once triggered: limit or market
+    #[serde(rename = "type")]
+    pub order_type: TradeType,
+    /// Trigger direction: loss or entry
+    pub stop: StopType,
+    /// Trigger price
+    pub stop_price: String,
+    /// Limit price, required for limit orders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    /// Quantity in base currency, required for limit orders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    /// Amount in quote currency, market orders only (one of size or funds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funds: Option<String>,
+    /// Time in force, limit orders only. Default is `GTC`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    /// Cancel after n seconds, the order timing strategy is GTT
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_after: Option<i64>,
+    /// passive order labels, disabled when the timing strategy is IOC or FOK
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+    /// Hidden order (not shown in order book)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<bool>,
+    /// Iceberg order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iceberg: Option<bool>,
+    /// Maximum visible quantity in iceberg orders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_size: Option<String>,
+    /// Self Trade Prevention strategy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stp: Option<Stp>,
+    /// Order placement remarks, length cannot exceed 20 characters (ASCII)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopOrderData {
+    /// The unique order id generated by the trading system
+    pub order_id: String,
+    /// The user self-defined order id
+    #[serde(default)]
+    pub client_oid: Option<String>,
+}
+
+/// Query for untriggered stop orders (`/api/v1/stop-order`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopOrderListRequest {
+    /// Symbol
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// Side: buy, sell
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<Side>,
+    /// Order type: limit, market
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub order_type: Option<TradeType>,
+    /// Trigger direction: loss, entry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<StopType>,
+    /// Comma-separated order ids
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_ids: Option<String>,
+    /// Start time (milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<i64>,
+    /// End time (milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<i64>,
+    /// Current page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_page: Option<i64>,
+    /// Number of results per page, default 50
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopOrderList {
+    /// Current request page
+    pub current_page: i64,
+    /// Number of results per request
+    pub page_size: i64,
+    /// Total number of records
+    pub total_num: i64,
+    /// Total number of pages
+    pub total_page: i64,
+    pub items: Vec<StopOrder>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopOrder {
+    /// Order id
+    pub id: String,
+    /// Symbol
+    pub symbol: String,
+    /// NEW: waiting for the trigger, TRIGGERED: placed in the order book
+    pub status: StopOrderStatus,
+    /// Order type: limit, market
+    #[serde(rename = "type")]
+    pub order_type: TradeType,
+    /// Side: buy, sell
+    pub side: Side,
+    /// Trigger direction: loss, entry
+    pub stop: StopType,
+    /// Trigger price
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub stop_price: Amount,
+    /// Limit price
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub price: Option<Amount>,
+    /// Quantity
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub size: Option<Amount>,
+    /// Funds
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub funds: Option<Amount>,
+    /// Time in force
+    #[serde(default)]
+    pub time_in_force: Option<TimeInForce>,
+    /// Cancel after n seconds
+    #[serde(default)]
+    pub cancel_after: Option<i64>,
+    pub post_only: bool,
+    pub hidden: bool,
+    pub iceberg: bool,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub visible_size: Option<Amount>,
+    /// The user self-defined order id
+    #[serde(default)]
+    pub client_oid: Option<String>,
+    #[serde(default)]
+    pub remark: Option<String>,
+    /// Trade type, e.g. TRADE
+    #[serde(default)]
+    pub trade_type: Option<OrderTradeType>,
+    /// Fee currency
+    #[serde(default)]
+    pub fee_currency: Option<String>,
+    /// Time the order was placed (nanoseconds)
+    #[serde(default)]
+    pub order_time: Option<i64>,
+    /// Creation time (milliseconds)
+    pub created_at: i64,
+    /// Trigger time (milliseconds), unset until triggered
+    #[serde(default)]
+    pub stop_trigger_time: Option<i64>,
+}
+
+string_enum! {
+    /// Stop order status.
+    pub enum StopOrderStatus {
+        New => "NEW",
+        Triggered => "TRIGGERED",
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanceledStopOrders {
+    /// Ids of the canceled stop orders
+    pub cancelled_order_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanceledStopClientOrder {
+    /// Id of the canceled stop order
+    pub cancelled_order_id: String,
+    /// The user self-defined order id
+    pub client_oid: String,
+}
+
+/// Dead Cancel Protection settings: open HF orders are canceled once `timeout` seconds pass
+/// without the settings being refreshed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DcpRequest {
+    /// Seconds before the orders are canceled, 5 to 86400; -1 disables DCP
+    pub timeout: i64,
+    /// Comma-separated symbols to protect, every symbol when unset (at most 50)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbols: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DcpData {
+    /// Server time (seconds)
+    pub current_time: i64,
+    /// Time the orders will be canceled if not refreshed (seconds)
+    pub trigger_time: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DcpSettings {
+    /// Timeout in seconds
+    pub timeout: i64,
+    /// Comma-separated protected symbols, empty for every symbol
+    #[serde(default)]
+    pub symbols: Option<String>,
+    /// Server time (seconds)
+    pub current_time: i64,
+    /// Time the orders will be canceled if not refreshed (seconds)
+    pub trigger_time: i64,
+}
+
+/// Query for the HF trade fills of a symbol (`/api/v1/hf/fills`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FillsRequest {
+    /// Symbol
+    pub symbol: String,
+    /// Only the fills of this order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    /// Side: buy, sell
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<Side>,
+    /// Order type: limit, market
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub order_type: Option<Type>,
+    /// The `lastId` of the previous page, used as the cursor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<i64>,
+    /// Default 20, maximum 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Start time (milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<i64>,
+    /// End time (milliseconds), the time range cannot exceed 7 days
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FillList {
+    /// Cursor for the next page, pass it back as `lastId`
+    #[serde(default)]
+    pub last_id: Option<i64>,
+    /// Fills, newest first
+    pub items: Vec<Fill>,
+}
+
+/// One execution of an order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Fill {
+    /// Id of the fill record, used as the pagination cursor
+    #[serde(deserialize_with = "crate::utils::num::int_lenient")]
+    pub id: i64,
+    /// Trade id, shared by both sides of the match
+    #[serde(deserialize_with = "crate::utils::num::int_lenient")]
+    pub trade_id: i64,
+    /// Order id
+    pub order_id: String,
+    /// Order id of the counterparty
+    pub counter_order_id: String,
+    /// Symbol
+    pub symbol: String,
+    /// Side: buy, sell
+    pub side: Side,
+    /// Whether the order added (maker) or removed (taker) liquidity
+    pub liquidity: Liquidity,
+    /// Order type: limit, market
+    #[serde(rename = "type")]
+    pub order_type: Type,
+    /// Whether the order was forced to take liquidity
+    pub force_taker: bool,
+    /// Execution price
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub price: Amount,
+    /// Execution size, in base currency
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub size: Amount,
+    /// Execution value (price * size), in quote currency
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub funds: Amount,
+    /// Fee charged for this fill
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub fee: Amount,
+    /// Fee rate applied
+    #[serde(deserialize_with = "crate::utils::num::amount")]
+    pub fee_rate: Amount,
+    /// Currency the fee is charged in
+    pub fee_currency: String,
+    /// Stop type, empty for regular orders
+    #[serde(default)]
+    pub stop: Option<String>,
+    /// Trade type, e.g. TRADE
+    pub trade_type: OrderTradeType,
+    /// Tax rate, only for Turkish and Thai users
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub tax_rate: Option<Amount>,
+    /// Tax, only for Turkish and Thai users
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub tax: Option<Amount>,
+    /// Time of the fill (milliseconds)
+    pub created_at: i64,
+}
+
+string_enum! {
+    /// Liquidity role of a fill.
+    pub enum Liquidity {
+        Maker => "maker",
+        Taker => "taker",
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveSymbols {
+    /// Symbols with at least one open HF order
+    pub symbols: Vec<String>,
+}
+
+/// Open HF orders across every symbol that has some.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OpenOrders {
+    /// Open orders keyed by symbol, sorted by symbol
+    pub by_symbol: BTreeMap<String, Vec<SpotDatum>>,
+}
+
+impl OpenOrders {
+    /// Total number of open orders.
+    pub fn len(&self) -> usize {
+        self.by_symbol.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Symbols with open orders.
+    pub fn symbols(&self) -> impl Iterator<Item = &str> {
+        self.by_symbol.keys().map(String::as_str)
+    }
+
+    /// Every open order, grouped by symbol.
+    pub fn iter(&self) -> impl Iterator<Item = &SpotDatum> {
+        self.by_symbol.values().flatten()
+    }
 }
 
 /// Order type
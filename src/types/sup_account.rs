@@ -13,6 +13,14 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    types::account::Permission,
+    utils::{
+        enums::{int_enum, string_enum},
+        num::Amount,
+    },
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubAccRequest {
@@ -24,13 +32,37 @@ pub struct SubAccRequest {
     pub passphrase: String,
     /// [Permissions](/docs-new/introduction)(Only General, Spot, Futures, Margin, Unified,
     /// InnerTransfer (Flex Transfer) permissions can be set
-    pub permission: Option<String>,
+    #[serde(default, with = "permission_list")]
+    pub permission: Option<Vec<Permission>>,
     /// Remarks (1–24 characters)
     pub remark: String,
     /// Sub-account name, create sub account name of API Key.
     pub sub_name: String,
 }
 
+/// (De)serialize a permission list as the comma separated string used by the API.
+mod permission_list {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::types::account::Permission;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Vec<Permission>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(list) => serializer.serialize_some(&Permission::join_list(list)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<Permission>>, D::Error> {
+        Ok(Option::<String>::deserialize(deserializer)?.map(|list| Permission::parse_list(&list)))
+    }
+}
+
 /// API expiration time
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Expire {
@@ -90,27 +122,55 @@ pub struct SubAccItem {
     pub access: String,
     /// Time of event
     pub created_at: i64,
-    pub hosted_status: String,
+    /// Hosting status, null for regular sub-accounts
+    #[serde(default)]
+    pub hosted_status: Option<HostedStatus>,
     /// Sub-account active permissions: If you do not have the corresponding permissions, you
     /// must log in to the sub-account and go to the corresponding web page to activate.
     pub opened_trade_types: Vec<String>,
     /// Remarks
     pub remarks: String,
     /// Sub-account; 2:Enable, 3:Frozen
-    pub status: i64,
+    pub status: SubAccStatus,
     /// Sub-account name
     pub sub_name: String,
     /// Sub-account Permissions
     pub trade_types: Vec<String>,
     /// Sub-account type
     #[serde(rename = "type")]
-    pub item_type: i64,
+    pub item_type: SubAccType,
     /// Sub-account UID
     pub uid: i64,
     /// Sub-account User ID
     pub user_id: String,
 }
 
+int_enum! {
+    /// Sub-account status.
+    pub enum SubAccStatus {
+        Enabled => 2,
+        Frozen => 3,
+    }
+}
+
+int_enum! {
+    /// Sub-account type.
+    pub enum SubAccType {
+        Normal => 0,
+        Robot => 1,
+        Novice => 2,
+        AssetManagement => 5,
+    }
+}
+
+string_enum! {
+    /// Hosting status of a sub-account managed by a third party.
+    pub enum HostedStatus {
+        Hosted => "HOSTED",
+        Default => "DEFAULT",
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubAccBalance {
@@ -133,19 +193,24 @@ pub struct SubAccBalance {
 #[serde(rename_all = "camelCase")]
 pub struct MainAccount {
     /// Funds available to withdraw or trade.
-    pub available: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub available: Option<Amount>,
     /// Total funds in an account.
-    pub balance: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub balance: Option<Amount>,
     /// The base currency amount.
-    pub base_amount: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub base_amount: Option<Amount>,
     /// Calculated on this currency.
     pub base_currency: Option<String>,
     /// The base currency price.
-    pub base_currency_price: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub base_currency_price: Option<Amount>,
     /// Currency
     pub currency: Option<String>,
     /// Funds on hold (not available for use).
-    pub holds: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub holds: Option<Amount>,
     pub tag: Option<String>,
 }
 
@@ -153,19 +218,24 @@ pub struct MainAccount {
 #[serde(rename_all = "camelCase")]
 pub struct MarginAccount {
     /// Funds available to withdraw or trade.
-    pub available: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub available: Option<Amount>,
     /// Total funds in an account.
-    pub balance: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub balance: Option<Amount>,
     /// The base currency amount.
-    pub base_amount: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub base_amount: Option<Amount>,
     /// Calculated on this currency.
     pub base_currency: Option<String>,
     /// The base currency price.
-    pub base_currency_price: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub base_currency_price: Option<Amount>,
     /// Currency
     pub currency: Option<String>,
     /// Funds on hold (not available for use).
-    pub holds: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub holds: Option<Amount>,
     pub tag: Option<String>,
 }
 
@@ -173,18 +243,23 @@ pub struct MarginAccount {
 #[serde(rename_all = "camelCase")]
 pub struct TradeAccount {
     /// Funds available to withdraw or trade.
-    pub available: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub available: Option<Amount>,
     /// Total funds in an account.
-    pub balance: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub balance: Option<Amount>,
     /// The base currency amount.
-    pub base_amount: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub base_amount: Option<Amount>,
     /// Calculated on this currency.
     pub base_currency: Option<String>,
     /// The base currency price.
-    pub base_currency_price: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub base_currency_price: Option<Amount>,
     /// Currency
     pub currency: Option<String>,
     /// Funds on hold (not available for use).
-    pub holds: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::num::opt_amount")]
+    pub holds: Option<Amount>,
     pub tag: Option<String>,
 }
@@ -32,20 +32,43 @@ pub struct TransferRequest {
 }
 
 /// Account type: MAIN, TRADE, CONTRACT, MARGIN, ISOLATED, MARGIN_V2, ISOLATED_V2
+///
+/// The account endpoints report the type in lowercase (main, trade, trade_hf, margin), which is
+/// accepted when deserializing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AccountType {
+    #[serde(alias = "contract")]
     Contract,
+    #[serde(alias = "isolated")]
     Isolated,
-    #[serde(rename = "ISOLATED_V2")]
+    #[serde(rename = "ISOLATED_V2", alias = "isolated_v2")]
     IsolatedV2,
+    #[serde(alias = "main")]
     Main,
+    #[serde(alias = "margin")]
     Margin,
-    #[serde(rename = "MARGIN_V2")]
+    #[serde(rename = "MARGIN_V2", alias = "margin_v2")]
     MarginV2,
+    #[serde(alias = "trade", alias = "trade_hf")]
     Trade,
 }
 
+impl AccountType {
+    /// The lowercase value expected by the `type` query parameter of the account endpoints.
+    pub fn as_query(&self) -> &'static str {
+        match self {
+            AccountType::Contract => "contract",
+            AccountType::Isolated => "isolated",
+            AccountType::IsolatedV2 => "isolated_v2",
+            AccountType::Main => "main",
+            AccountType::Margin => "margin",
+            AccountType::MarginV2 => "margin_v2",
+            AccountType::Trade => "trade",
+        }
+    }
+}
+
 /// Transfer type
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
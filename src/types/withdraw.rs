@@ -13,6 +13,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::utils::enums::string_enum;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawRequest {
@@ -36,7 +38,7 @@ pub struct WithdrawRequest {
     /// platform (transaction fee: 0.0001BTC), if the balance in your main account is
     /// insufficient, the system will deduct the transaction fees from your withdrawal amount. In
     /// this case, you will be receiving 0.9999BTC.
-    pub fee_deduct_type: Option<String>,
+    pub fee_deduct_type: Option<FeeDeductType>,
     /// Internal withdrawal or not. Default: False
     pub is_inner: Option<bool>,
     /// Address remark. If there’s no remark, it is empty. When you withdraw from other platforms
@@ -53,6 +55,16 @@ pub struct WithdrawRequest {
     pub withdraw_type: WithdrawType,
 }
 
+string_enum! {
+    /// Where the withdrawal fee is taken from.
+    pub enum FeeDeductType {
+        /// From the withdrawal amount.
+        Internal => "INTERNAL",
+        /// From the main account.
+        External => "EXTERNAL",
+    }
+}
+
 /// Withdrawal type, ADDRESS (withdrawal address), UID, MAIL (email), PHONE (mobile phone
 /// number). Note: If you withdraw by uid/mail/phone, there will be rate limits: 3 times/10
 /// seconds, 50 times/24 hours (calculated on a rolling basis based on the first request time)
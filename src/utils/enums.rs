@@ -0,0 +1,168 @@
+/// Define an enum over the string values of an API field, with an `Unknown(String)` variant
+/// that keeps values this crate does not know yet, so new exchange values never break
+/// deserialization.
+///
+/// Generates `as_str`, `FromStr`, `Display`, `Serialize` and `Deserialize`.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $( $(#[$vmeta:meta])* $variant:ident => $value:literal, )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $( $(#[$vmeta])* $variant, )*
+            /// A value this crate does not know yet.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The value used by the API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $( $name::$variant => $value, )*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok(match value {
+                    $( $value => $name::$variant, )*
+                    other => $name::Unknown(other.to_string()),
+                })
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+                let Ok(parsed) = value.parse::<$name>();
+                Ok(parsed)
+            }
+        }
+    };
+}
+
+/// Same as `string_enum`, for fields the API sends as integer codes. Unknown codes are kept
+/// in `Unknown` as their decimal representation.
+///
+/// Generates `code`, `Serialize` and `Deserialize` (from a number or a numeric string).
+macro_rules! int_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $( $(#[$vmeta:meta])* $variant:ident => $value:literal, )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $( $(#[$vmeta])* $variant, )*
+            /// A code this crate does not know yet.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The code used by the API, `None` for an unknown non-numeric value.
+            pub fn code(&self) -> Option<i64> {
+                match self {
+                    $( $name::$variant => Some($value), )*
+                    $name::Unknown(value) => value.parse().ok(),
+                }
+            }
+
+            fn as_unknown(&self) -> &str {
+                match self {
+                    $name::Unknown(value) => value,
+                    _ => "",
+                }
+            }
+
+            fn from_code(code: i64) -> Self {
+                match code {
+                    $( $value => $name::$variant, )*
+                    other => $name::Unknown(other.to_string()),
+                }
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self.code() {
+                    Some(code) => serializer.serialize_i64(code),
+                    None => serializer.serialize_str(self.as_unknown()),
+                }
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(::serde::Deserialize)]
+                #[serde(untagged)]
+                enum Raw {
+                    Int(i64),
+                    Str(String),
+                }
+
+                Ok(match <Raw as ::serde::Deserialize>::deserialize(deserializer)? {
+                    Raw::Int(code) => $name::from_code(code),
+                    Raw::Str(raw) => match raw.parse::<i64>() {
+                        Ok(code) => $name::from_code(code),
+                        Err(_) => $name::Unknown(raw),
+                    },
+                })
+            }
+        }
+    };
+}
+
+pub(crate) use {int_enum, string_enum};
+
+#[cfg(test)]
+mod test {
+    use crate::types::{
+        margin::MarginStatus, market::AnnouncementType, spot::TimeInForce,
+        sup_account::SubAccStatus,
+    };
+
+    #[test]
+    fn test_unknown_fallback() {
+        let known: TimeInForce = serde_json::from_str(r#""GTC""#).unwrap();
+        assert_eq!(known, TimeInForce::Gtc);
+
+        let unknown: TimeInForce = serde_json::from_str(r#""GTX""#).unwrap();
+        assert_eq!(unknown, TimeInForce::Unknown("GTX".to_string()));
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), r#""GTX""#);
+
+        let status: SubAccStatus = serde_json::from_str("3").unwrap();
+        assert_eq!(status, SubAccStatus::Frozen);
+        let status: SubAccStatus = serde_json::from_str(r#""9""#).unwrap();
+        assert_eq!(status.code(), Some(9));
+        assert_eq!(serde_json::to_string(&status).unwrap(), "9");
+
+        let margin: MarginStatus = serde_json::from_str(r#""FROZEN""#).unwrap();
+        assert_eq!(margin, MarginStatus::Unknown("FROZEN".to_string()));
+        let types: Vec<AnnouncementType> =
+            serde_json::from_str(r#"["new-listings","some-future-type"]"#).unwrap();
+        assert_eq!(types[0], AnnouncementType::NewListings);
+        assert_eq!(types[1].as_str(), "some-future-type");
+    }
+}
@@ -1,5 +1,7 @@
 use thiserror::Error as ThisError;
 
+use crate::types::{account::Permission, spot::OrderViolation};
+
 /// Defines Error types.
 #[derive(ThisError, Debug)]
 pub enum KucoinErrors {
@@ -10,9 +12,26 @@ pub enum KucoinErrors {
     #[error("Account tag is required for {0} ISOLATED account")]
     MissingIsolatedTag(String),
 
+    /// The request was rejected locally before being sent.
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+
+    #[error("URL-ENCODE-ERROR: {0}")]
+    UrlEncodeError(#[from] serde_urlencoded::ser::Error),
+
+    /// The API answered with a non-success code or without data.
+    #[error("KUCOIN-API-ERROR: {code} {msg}")]
+    ApiError { code: String, msg: String },
+
+    #[error("API key lacks the permissions: {0:?}")]
+    MissingPermissions(Vec<Permission>),
+
+    /// The order breaks the symbol trading rules; every violation found is listed.
+    #[error("Order violates the symbol rules: {0:?}")]
+    OrderValidation(Vec<OrderViolation>),
+
     #[error("REQWEST-ERROR: {0}")]
     ReqwestError(#[from] reqwest::Error),
 }
 /// Alias Type for Results with Error Handler
 pub type KucoinResults<T> = Result<T, KucoinErrors>;
-
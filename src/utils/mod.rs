@@ -1,3 +1,5 @@
 pub mod auth;
+pub mod enums;
 pub mod errors;
+pub mod num;
 pub mod time;
@@ -0,0 +1,181 @@
+use serde::{Deserialize, Deserializer, de::Error};
+
+/// Deserialize an integer sent either as a JSON number or as a numeric string.
+pub fn int_lenient<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Int(i64),
+        Str(String),
+    }
+
+    match Raw::deserialize(deserializer)? {
+        Raw::Int(value) => Ok(value),
+        Raw::Str(raw) => raw.parse::<i64>().map_err(Error::custom),
+    }
+}
+
+/// Amount type of the response structs (prices, sizes, funds, fees, balances): an exact
+/// decimal, parsed from the strings sent by the API without going through `f64`.
+pub type Amount = rust_decimal::Decimal;
+
+/// Values accepted by request builders for prices, sizes and amounts.
+///
+/// Implemented for `f64`, for numeric strings (sent verbatim) and for `Decimal`. Prefer the
+/// last two when the value comes out of arithmetic: an `f64` such as `0.1 + 0.2` is sent as
+/// "0.30000000000000004".
+pub trait ToAmount {
+    /// The value as sent to the API.
+    fn to_amount(&self) -> String;
+}
+
+impl ToAmount for f64 {
+    fn to_amount(&self) -> String {
+        // `Display` for f64 never uses exponent notation.
+        self.to_string()
+    }
+}
+
+impl ToAmount for &str {
+    fn to_amount(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToAmount for String {
+    fn to_amount(&self) -> String {
+        self.clone()
+    }
+}
+
+impl ToAmount for Amount {
+    fn to_amount(&self) -> String {
+        self.normalize().to_string()
+    }
+}
+
+/// Parse a numeric string (plain or scientific notation) into an `Amount`.
+pub(crate) fn parse_amount(raw: &str) -> Option<Amount> {
+    use std::str::FromStr;
+    Amount::from_str(raw)
+        .or_else(|_| Amount::from_scientific(raw))
+        .ok()
+}
+
+/// An amount sent as a numeric string, or by a few endpoints as a JSON number.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawAmount {
+    Str(String),
+    Num(f64),
+}
+
+impl RawAmount {
+    fn parse<E: Error>(self) -> Result<Option<Amount>, E> {
+        let raw = match self {
+            RawAmount::Str(raw) if raw.is_empty() => return Ok(None),
+            RawAmount::Str(raw) => raw,
+            // The shortest representation that round-trips, e.g. 0.1 rather than 0.1000000000000000055.
+            RawAmount::Num(value) => value.to_string(),
+        };
+        parse_amount(&raw)
+            .map(Some)
+            .ok_or_else(|| E::custom(format!("invalid amount: {}", raw)))
+    }
+}
+
+/// Deserialize a numeric string (or number) into an `Amount`.
+pub fn amount<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Amount, D::Error> {
+    RawAmount::deserialize(deserializer)?
+        .parse()?
+        .ok_or_else(|| Error::custom("invalid amount: empty string"))
+}
+
+/// Deserialize an optional numeric string (or number) into an `Amount`, treating null and ""
+/// as `None`. Pair with `#[serde(default)]` for fields the API may omit.
+pub fn opt_amount<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Amount>, D::Error> {
+    match Option::<RawAmount>::deserialize(deserializer)? {
+        Some(raw) => raw.parse(),
+        None => Ok(None),
+    }
+}
+
+/// Whether `value` is a whole multiple of `increment`. A non-positive increment accepts all.
+pub(crate) fn is_multiple(value: Amount, increment: Amount) -> bool {
+    if increment <= Amount::ZERO {
+        return true;
+    }
+    (value % increment).is_zero()
+}
+
+/// Round `value` down to a multiple of `increment`, e.g. a size to `baseIncrement` so it
+/// never exceeds the available balance. A non-positive increment returns `value` unchanged.
+pub fn floor_to_increment(value: Amount, increment: Amount) -> Amount {
+    if increment <= Amount::ZERO {
+        return value;
+    }
+    ((value / increment).floor() * increment).normalize()
+}
+
+/// Round `value` to the nearest multiple of `increment` (halves away from zero), e.g. a price
+/// to `priceIncrement`. A non-positive increment returns `value` unchanged.
+pub fn round_to_increment(value: Amount, increment: Amount) -> Amount {
+    if increment <= Amount::ZERO {
+        return value;
+    }
+    let steps = (value / increment)
+        .round_dp_with_strategy(0, rust_decimal::RoundingStrategy::MidpointAwayFromZero);
+    (steps * increment).normalize()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::fixtures::amt;
+
+    #[test]
+    fn test_increment_rounding() {
+        assert_eq!(
+            floor_to_increment(amt("0.123456789"), amt("0.0001")),
+            amt("0.1234")
+        );
+        assert_eq!(
+            round_to_increment(amt("67000.25"), amt("0.1")),
+            amt("67000.3")
+        );
+        assert_eq!(floor_to_increment(amt("0.3"), amt("0.1")), amt("0.3"));
+        assert_eq!(floor_to_increment(amt("5"), amt("0")), amt("5"));
+        assert!(is_multiple(amt("0.3"), amt("0.1")));
+        assert!(!is_multiple(amt("0.35"), amt("0.1")));
+        assert!(is_multiple(amt("1000000.5"), amt("0.00000001")));
+        assert!(!is_multiple(amt("1000000.500000005"), amt("0.00000001")));
+        assert!(!is_multiple(amt("123456789.15"), amt("0.1")));
+    }
+
+    #[test]
+    fn test_parse_amount_forms() {
+        #[derive(Deserialize)]
+        struct Row {
+            #[serde(deserialize_with = "amount")]
+            a: Amount,
+            #[serde(default, deserialize_with = "opt_amount")]
+            b: Option<Amount>,
+        }
+
+        let row: Row = serde_json::from_str(r#"{"a":"0.1","b":""}"#).unwrap();
+        assert_eq!(row.a, amt("0.1"));
+        assert_eq!(row.b, None);
+        let row: Row = serde_json::from_str(r#"{"a":0.1,"b":2.5}"#).unwrap();
+        assert_eq!(row.a, amt("0.1"));
+        assert_eq!(row.b, Some(amt("2.5")));
+        assert!(serde_json::from_str::<Row>(r#"{"a":"x"}"#).is_err());
+    }
+
+    #[test]
+    fn test_to_amount() {
+        assert_eq!(0.0000001.to_amount(), "0.0000001");
+        assert_eq!("0.30".to_amount(), "0.30");
+        assert!(parse_amount("1e-7").is_some());
+        assert!(parse_amount("abc").is_none());
+    }
+}